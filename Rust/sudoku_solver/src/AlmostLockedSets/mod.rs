@@ -0,0 +1,298 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{sudokuUnit, allUnits, seesCell, cellName};
+use super::Deductions::deduction;
+use super::Subsets::getCombinations;
+use super::StrategyRunners::runThreadsUntilStable;
+
+// Maximum number of cells of the almost locked sets, the bigger sets are rarely needed and make the search much slower
+pub const MAX_ALS_SIZE: i8 = 4;
+
+// Patterns based on the almost locked sets of the sudoku
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum alsPattern {
+    XZ, XYWing, DeathBlossom
+}
+
+// Almost locked set: n cells of the same unit with n+1 values, so if a value is deleted the other ones are locked in the cells
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct almostLockedSet {
+    pub unit: sudokuUnit,
+    pub cells: Vec<(usize, usize)>,
+    pub values: candidateSet
+}
+
+impl almostLockedSet {
+    // Return the cells of the set that contain the given value
+    pub fn cellsWithValue(&self, sudokuCopy: &[[candidateSet; 9]; 9], value: i8) -> Vec<(usize, usize)> {
+        return self.cells.iter().filter(|&&(i, j)| sudokuCopy[i][j].contains(value)).cloned().collect();
+    }
+
+    // Check if the two sets have at least a cell in common
+    pub fn overlaps(&self, other: &almostLockedSet) -> bool {
+        return self.cells.iter().any(|cell| other.cells.contains(cell));
+    }
+}
+
+impl std::fmt::Display for almostLockedSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let cellNames: Vec<String> = self.cells.iter().map(|&(i, j)| cellName(i, j)).collect();
+        return write!(f, "{} {}", cellNames.join(" "), self.values);
+    }
+}
+
+// Index of the almost locked sets of a sudoku and of their restricted common values. A value is a restricted common
+// value of two sets that don't overlap if every cell of a set with the value sees every cell of the other set with
+// the value, so it can be only in one of them
+pub struct alsIndex {
+    pub sets: Vec<almostLockedSet>,
+    restrictedCommons: Vec<Vec<candidateSet>>
+}
+
+impl alsIndex {
+    // Find the almost locked sets of every unit and their restricted common values
+    pub fn new(sudokuCopy: &[[candidateSet; 9]; 9]) -> alsIndex {
+        let mut sets: Vec<almostLockedSet> = Vec::new();
+        for unit in allUnits() {
+            let cells: Vec<(usize, usize)> = unit.cells().iter().filter(|&&(i, j)| sudokuCopy[i][j].len() > 1).cloned().collect();
+            for size in 1..=MAX_ALS_SIZE.min(cells.len() as i8) {
+                for combination in getCombinations((0..cells.len() as i8).collect(), size, 0) {
+                    let setCells: Vec<(usize, usize)> = combination.iter().map(|&position| cells[position as usize]).collect();
+                    let values = setCells.iter().fold(candidateSet::new(), |values, &(i, j)| values.union(sudokuCopy[i][j]));
+                    // The sets of two cells in the same row and box are found in both units
+                    if values.len() == setCells.len() + 1 && !sets.iter().any(|set| set.cells == setCells) {
+                        sets.push(almostLockedSet{unit, cells: setCells, values});
+                    }
+                }
+            }
+        }
+        let mut restrictedCommons = vec![vec![candidateSet::new(); sets.len()]; sets.len()];
+        for first in 0..sets.len() {
+            for second in first+1..sets.len() {
+                if sets[first].overlaps(&sets[second]) {
+                    continue;
+                }
+                for value in sets[first].values.intersection(sets[second].values) {
+                    let secondCells = sets[second].cellsWithValue(sudokuCopy, value);
+                    if sets[first].cellsWithValue(sudokuCopy, value).iter().all(|&cell| secondCells.iter().all(|&other| seesCell(cell, other))) {
+                        restrictedCommons[first][second].insert(value);
+                        restrictedCommons[second][first].insert(value);
+                    }
+                }
+            }
+        }
+        return alsIndex{sets, restrictedCommons};
+    }
+
+    // Return the restricted common values of the two sets in the given positions
+    pub fn restrictedCommons(&self, first: usize, second: usize) -> candidateSet {
+        return self.restrictedCommons[first][second];
+    }
+}
+
+// Find all the given patterns, until there are no more values to delete. The almost locked sets are found once for
+// every pass and shared by the threads of the patterns
+pub fn findAlmostLockedSets(ioManager: Arc<RwLock<sudokuIOManager>>, patterns: &[alsPattern]) -> Result<(), sudokuError> {
+    return runThreadsUntilStable(ioManager, patterns, alsIndex::new, findAlsPattern);
+}
+
+// Find every occurrence of the given pattern and delete the corresponding values, updates signals if there are deleted values
+pub fn findAlsPattern(ioManager: Arc<RwLock<sudokuIOManager>>, pattern: alsPattern, sudokuCopy: &[[candidateSet; 9]; 9], index: &alsIndex) -> Result<bool, sudokuError> {
+    let deductions = match pattern {
+        alsPattern::XZ => findAlsXZ(sudokuCopy, index),
+        alsPattern::XYWing => findAlsXYWings(sudokuCopy, index),
+        alsPattern::DeathBlossom => findDeathBlossoms(sudokuCopy, index)
+    };
+    let updates = !deductions.is_empty();
+    let readManager = ioManager.read().unwrap();
+    for deductionVar in deductions {
+        readManager.ApplyDeduction(deductionVar)?;
+    }
+    return Ok(updates);
+}
+
+// Find the ALS-XZ: two sets with a restricted common value x, which can be in only one of them, so the other set is
+// locked. Then a value z of both sets must be in one of them, and it can be deleted from the cells that see all the
+// cells of the sets with z. If the sets have two restricted common values both sets are locked, so every value of a
+// set can be deleted from the cells that see all the cells of the set with that value
+pub fn findAlsXZ(sudokuCopy: &[[candidateSet; 9]; 9], index: &alsIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    for first in 0..index.sets.len() {
+        for second in first+1..index.sets.len() {
+            let restrictedValues = index.restrictedCommons(first, second);
+            if restrictedValues.is_empty() {
+                continue;
+            }
+            let (firstSet, secondSet) = (&index.sets[first], &index.sets[second]);
+            let description = format!("sets {} and {}, restricted common values {}", firstSet, secondSet, restrictedValues);
+            let mut xz = deduction::new("ALS-XZ", description);
+            let excluded: Vec<(usize, usize)> = firstSet.cells.iter().chain(secondSet.cells.iter()).cloned().collect();
+            if restrictedValues.len() == 1 {
+                for z in firstSet.values.intersection(secondSet.values).difference(restrictedValues) {
+                    let mut zCells = firstSet.cellsWithValue(sudokuCopy, z);
+                    zCells.extend(secondSet.cellsWithValue(sudokuCopy, z));
+                    xz.addCommonPeersEliminations(sudokuCopy, z, &zCells, &excluded);
+                }
+            } else {
+                for x in restrictedValues {
+                    let mut xCells = firstSet.cellsWithValue(sudokuCopy, x);
+                    xCells.extend(secondSet.cellsWithValue(sudokuCopy, x));
+                    xz.addCommonPeersEliminations(sudokuCopy, x, &xCells, &excluded);
+                }
+                for set in [firstSet, secondSet].iter() {
+                    for value in set.values.difference(restrictedValues) {
+                        xz.addCommonPeersEliminations(sudokuCopy, value, &set.cellsWithValue(sudokuCopy, value), &excluded);
+                    }
+                }
+            }
+            if !xz.eliminations.is_empty() {
+                deductions.push(xz);
+            }
+        }
+    }
+    return deductions;
+}
+
+// Find the ALS-XY-Wings: a pivot set has a restricted common value x with a set and a different restricted common value
+// y with another set. One of the two sets is locked, so a value z of both can be deleted from the cells that see all
+// the cells of the two sets with z
+pub fn findAlsXYWings(sudokuCopy: &[[candidateSet; 9]; 9], index: &alsIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    for pivot in 0..index.sets.len() {
+        let linkedSets: Vec<usize> = (0..index.sets.len()).filter(|&set| !index.restrictedCommons(pivot, set).is_empty()).collect();
+        for (position, &first) in linkedSets.iter().enumerate() {
+            for &second in linkedSets[position+1..].iter() {
+                let (firstSet, secondSet) = (&index.sets[first], &index.sets[second]);
+                if firstSet.overlaps(secondSet) {
+                    continue;
+                }
+                for x in index.restrictedCommons(pivot, first) {
+                    for y in index.restrictedCommons(pivot, second).difference(candidateSet::single(x)) {
+                        let description = format!("pivot {}, sets {} and {}, restricted common values {} and {}", index.sets[pivot], firstSet, secondSet, x, y);
+                        let mut wing = deduction::new("ALS-XY-Wing", description);
+                        let excluded: Vec<(usize, usize)> = firstSet.cells.iter().chain(secondSet.cells.iter()).cloned().collect();
+                        for z in firstSet.values.intersection(secondSet.values).difference(candidateSet::fromValues(&[x, y])) {
+                            let mut zCells = firstSet.cellsWithValue(sudokuCopy, z);
+                            zCells.extend(secondSet.cellsWithValue(sudokuCopy, z));
+                            wing.addCommonPeersEliminations(sudokuCopy, z, &zCells, &excluded);
+                        }
+                        if !wing.eliminations.is_empty() {
+                            deductions.push(wing);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return deductions;
+}
+
+// Find the Death Blossoms: every value of a stem cell has a petal, a set in which every cell with that value sees the
+// stem. The stem has one of its values, which is deleted from its petal, so one of the petals is locked. Then a value
+// z of every petal can be deleted from the cells that see all the cells of the petals with z
+pub fn findDeathBlossoms(sudokuCopy: &[[candidateSet; 9]; 9], index: &alsIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    for i in 0..9 {
+        for j in 0..9 {
+            let stemValues = sudokuCopy[i][j];
+            if stemValues.len() < 2 || stemValues.len() > 3 {
+                continue;
+            }
+            for z in candidateSet::all().difference(stemValues) {
+                // Save the possible petals of every value of the stem, which must contain z
+                let petals: Vec<Vec<usize>> = stemValues.iter().map(|value| (0..index.sets.len()).filter(|&set| {
+                    let petal = &index.sets[set];
+                    return petal.values.contains(z) && petal.values.contains(value) && !petal.cells.contains(&(i, j)) &&
+                        petal.cellsWithValue(sudokuCopy, value).iter().all(|&cell| seesCell(cell, (i, j)));
+                }).collect()).collect();
+                if petals.iter().any(|valuePetals| valuePetals.is_empty()) {
+                    continue;
+                }
+                let mut chosen = Vec::new();
+                findBlossomPetals(sudokuCopy, index, (i, j), z, &petals, &mut chosen, &mut deductions);
+            }
+        }
+    }
+    return deductions;
+}
+
+// Choose a petal for every value of the stem, after the petals already chosen, and add the deduction of the first
+// complete blossom that deletes at least a value. Return true if the blossom has been found
+fn findBlossomPetals(sudokuCopy: &[[candidateSet; 9]; 9], index: &alsIndex, stem: (usize, usize), z: i8, petals: &[Vec<usize>], chosen: &mut Vec<usize>, deductions: &mut Vec<deduction>) -> bool {
+    // The cells with z of the chosen petals
+    let zCells: Vec<(usize, usize)> = chosen.iter().flat_map(|&petal| index.sets[petal].cellsWithValue(sudokuCopy, z)).collect();
+    let mut excluded: Vec<(usize, usize)> = chosen.iter().flat_map(|&petal| index.sets[petal].cells.clone()).collect();
+    excluded.push(stem);
+    let mut blossom = deduction::new("Death Blossom", String::new());
+    blossom.addCommonPeersEliminations(sudokuCopy, z, &zCells, &excluded);
+    // If no cell sees all the cells with z the other petals can't delete any value
+    if blossom.eliminations.is_empty() {
+        return false;
+    }
+    if chosen.len() == petals.len() {
+        let petalNames: Vec<String> = chosen.iter().map(|&petal| index.sets[petal].to_string()).collect();
+        blossom.description = format!("stem {} {}, petals {}, value {}", cellName(stem.0, stem.1), sudokuCopy[stem.0][stem.1], petalNames.join(", "), z);
+        deductions.push(blossom);
+        return true;
+    }
+    for &petal in petals[chosen.len()].iter() {
+        if chosen.iter().any(|&other| index.sets[other].overlaps(&index.sets[petal])) {
+            continue;
+        }
+        chosen.push(petal);
+        if findBlossomPetals(sudokuCopy, index, stem, z, petals, chosen, deductions) {
+            return true;
+        }
+        chosen.pop();
+    }
+    return false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, eliminationsOf, descriptionsOf};
+
+    // Return a sudoku in which r1c1 has only 1 and 2, r1c5 only 1 and 3, and r5c1 only 2 and 3. r1c1 sees the other two
+    // cells, so one of them is 3 and r5c5 can't be 3
+    fn wingGrid() -> [[candidateSet; 9]; 9] {
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(0, 4)]);
+        setValues(&mut grid, &[2, 3], &[(4, 0)]);
+        return grid;
+    }
+
+    #[test]
+    fn alsXZ() {
+        // The set r1c1 {1,2} and the set r5c1 r5c5 {1,2,3} have the restricted common value 1, so one of r1c1 and r5c5
+        // is 2 and r1c5 can't be 2. The same cells form also the sets r5c5 {2,3} and r1c1 r5c1 {1,2,3}
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(4, 0)]);
+        setValues(&mut grid, &[2, 3], &[(4, 4)]);
+        let deductions = findAlsXZ(&grid, &alsIndex::new(&grid));
+        assert_eq!(descriptionsOf(&deductions), vec!["ALS-XZ: sets r1c1 {1,2} and r5c1 r5c5 {1,2,3}, restricted common values {1}",
+                                                     "ALS-XZ: sets r5c5 {2,3} and r1c1 r5c1 {1,2,3}, restricted common values {3}"]);
+        assert_eq!(eliminationsOf(&deductions[..1]), vec![(0, 4, 2)]);
+        assert_eq!(eliminationsOf(&deductions[1..]), vec![(0, 4, 2)]);
+    }
+
+    #[test]
+    fn alsXYWing() {
+        let grid = wingGrid();
+        let deductions = findAlsXYWings(&grid, &alsIndex::new(&grid));
+        assert_eq!(descriptionsOf(&deductions), vec!["ALS-XY-Wing: pivot r1c1 {1,2}, sets r1c5 {1,3} and r5c1 {2,3}, restricted common values 1 and 2"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(4, 4, 3)]);
+    }
+
+    #[test]
+    fn deathBlossom() {
+        let grid = wingGrid();
+        let deductions = findDeathBlossoms(&grid, &alsIndex::new(&grid));
+        assert_eq!(descriptionsOf(&deductions), vec!["Death Blossom: stem r1c1 {1,2}, petals r1c5 {1,3}, r5c1 {2,3}, value 3"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(4, 4, 3)]);
+    }
+}
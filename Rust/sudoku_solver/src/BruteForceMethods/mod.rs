@@ -1,116 +1,260 @@
 extern crate crossbeam;
 extern crate num_bigint;
 extern crate num_traits;
-use crossbeam::crossbeam_channel::{Sender, Receiver, unbounded};
+use crossbeam::crossbeam_channel::{Sender, Receiver, TryRecvError, bounded, unbounded};
 use num_bigint::BigInt;
 use num_traits::One;
-use super::SudokuManager::{sudoku};
+use super::SudokuManager::sudoku;
+use super::CandidateSet::candidateSet;
 use super::SudokuIOManager::sudokuIOManager;
+use super::SudokuError::sudokuError;
 use super::CheckSudokuMethods;
 use super::ConstraintsElimination::sudokuConstraintsElimination;
-use std::sync::{Arc, RwLock, Mutex};
+use std::sync::{Arc, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
-// Get the solution using brute force, creating a specific channel and calling "searchSolution" as a goroutine
-pub fn bruteForceSolving(ioManagerPointer: Arc<RwLock<sudokuIOManager>>) -> sudokuIOManager {
-    let (solutionChannelSender, solutionChannelReceiver) = unbounded();
-    // Create a channel to send a stop signal to the active threads. The size is arbitrary, its purpose is to make the channel non-blocking
-    let (stopChannelSender, stopChannelReceiver) = unbounded();
-    let stopChannelReceiver = Arc::new(Mutex::new(stopChannelReceiver));
-    let stopChannelSender = Arc::new(Mutex::new(stopChannelSender));
-    {
-        let ioManager = ioManagerPointer.read().unwrap();
-        let sudokuTable = ioManager.GetSudoku();
-        let stopChannelSenderClone = stopChannelSender.clone();
-        crossbeam::scope(|scope| {
-            scope.spawn(move |_var| {searchSolution(sudokuTable, &solutionChannelSender, stopChannelSenderClone, stopChannelReceiver.clone())});
-        });
-    }
-    let sudokuSolution = solutionChannelReceiver.recv().unwrap();
-    // Send a stop signal to the running threads, because there is already a correct solution.
-    // If there isn't a running thread, an error will be returned because the channel receiver will be destroyed. Since this is not a problem, the case isn't handled.
-    stopChannelSender.lock().unwrap().send(true);
-
-    // Save and return the obtained solution in a new sudokuIOManager
-    let sudokuContainer = sudoku::newContainer(sudokuSolution);
-    return sudokuIOManager::new(sudokuContainer);
+// Outcome of the brute force search
+pub enum bruteForceResult {
+    // A solution has been found, it is saved in a new sudokuIOManager
+    Solved(Box<sudokuIOManager>),
+    // Every branch of the search has been analyzed without finding a solution
+    Unsolvable,
+    // The search has been stopped before finding a solution, because the time limit has been reached
+    Cancelled
 }
 
-// Recursive function, called as a subroutine in order to parallelize the search
-pub fn searchSolution(sudoku: [[Vec<i8>; 9]; 9], solutionChannel: &Sender<[[Vec<i8>; 9]; 9]>, stopChannelSender: Arc<Mutex<Sender<bool>>>, stopChannelReceiver: Arc<Mutex<Receiver<bool>>>) {
-    let mut i = 0;
-    let mut j = 0;
-    // Find the first cell without a definitive value, if it doesn't exist then "i" will reach the value 9
-    while i < 9 && sudoku[i][j].len() == 1 {
-        // If there is a signal of stop, kill the thread
-        let res = stopChannelReceiver.lock().unwrap().try_recv();
-        // If the operation turns out not to be ready, retry
-        if let Err(e) = res {
-            if e.is_empty() {
-                j += 1;
-                if j == 9 {
-                    i += 1;
-                    j = 0;
-                }
+// Get the solution using brute force, calling "searchSolution" as a goroutine.
+// If timeLimit is given, the search is stopped when that time has passed
+pub fn bruteForceSolving(ioManagerPointer: Arc<RwLock<sudokuIOManager>>, timeLimit: Option<Duration>) -> Result<bruteForceResult, sudokuError> {
+    let sudokuTable = ioManagerPointer.read().unwrap().GetSudoku()?;
+    // A complete sudoku that breaks the rules must not be returned as a solution
+    let sudokuTable = match prepareSearch(sudokuTable)? {
+        Some(sudokuTable) => sudokuTable,
+        None => return Ok(bruteForceResult::Unsolvable)
+    };
+    let mut search = runSearch(sudokuTable, 1, timeLimit)?;
+
+    match search.solutions.pop() {
+        // Save and return the obtained solution in a new sudokuIOManager
+        Some(sudokuSolution) => return Ok(bruteForceResult::Solved(Box::new(sudokuIOManager::new(sudoku::newContainer(sudokuSolution))))),
+        // Without a solution, the search is a proof that it doesn't exist only if no branch has been stopped
+        None if search.completed => return Ok(bruteForceResult::Unsolvable),
+        None => return Ok(bruteForceResult::Cancelled)
+    }
+}
+
+// Count the solutions of the sudoku, stopping the search when "limit" solutions have been found.
+// If timeLimit is given and that time passes before the count is certain, a timeout error is returned
+pub fn countSolutions(sudoku: [[candidateSet; 9]; 9], limit: usize, timeLimit: Option<Duration>) -> Result<usize, sudokuError> {
+    let sudokuTable = match prepareSearch(sudoku)? {
+        Some(sudokuTable) if limit > 0 => sudokuTable,
+        _ => return Ok(0)
+    };
+    let search = runSearch(sudokuTable, limit, timeLimit)?;
+    if search.solutions.len() < limit && !search.completed {
+        return Err(sudokuError::Timeout);
+    }
+    return Ok(search.solutions.len());
+}
+
+// Check if the sudoku has exactly one solution
+pub fn isUnique(sudoku: [[candidateSet; 9]; 9], timeLimit: Option<Duration>) -> Result<bool, sudokuError> {
+    return Ok(countSolutions(sudoku, 2, timeLimit)? == 1);
+}
+
+// Iterator over the solutions of a sudoku, which are found by a brute force search running in a separate thread.
+// The search waits until the next solution is requested, so the solutions are never collected in memory
+pub struct solutionsIterator {
+    solutionChannel: Option<Receiver<[[candidateSet; 9]; 9]>>,
+    // The stop signal is the closure of this channel
+    stopChannel: Option<Sender<()>>,
+    searchThread: Option<JoinHandle<Result<bool, sudokuError>>>,
+    // Number of solutions that can still be returned, without a value every solution is returned
+    remaining: Option<usize>
+}
+
+impl Iterator for solutionsIterator {
+    type Item = Result<sudoku, sudokuError>;
+
+    fn next(&mut self) -> Option<Result<sudoku, sudokuError>> {
+        if self.remaining == Some(0) {
+            // The search has been stopped on purpose, so its result isn't relevant
+            let _ = self.stop();
+            return None;
+        }
+        let received = match &self.solutionChannel {
+            Some(solutionChannel) => solutionChannel.recv().ok(),
+            None => None
+        };
+        match received {
+            Some(solution) => {
+                self.remaining = self.remaining.map(|remaining| remaining - 1);
+                return Some(Ok(sudoku::newContainer(solution)));
+            }
+            // The search is over, return its error if there is one
+            None => match self.stop() {
+                Err(error) => return Some(Err(error)),
+                Ok(()) => return None
             }
-        } else if res.unwrap() == true {
-            // Send a stop signal to the running threads, because the signal received has been consumed.
-            // If there isn't a running thread, an error will be returned because the channel receiver will be destroyed. Since this is not a problem, the case isn't handled.
-            stopChannelSender.lock().unwrap().send(true);
-            return;
         }
     }
-    // If "i" has value 9 then the sudoku is already a solution, so it can be saved in the solution channel.
-	// If there is already a solution in the channel, this upload will be ignored by the receiver
-    if i == 9 {
-        solutionChannel.send(sudoku).unwrap();
-    } else {
-        crossbeam::scope(|scope| {
-            // For every possible value of the found cell, copy the matrix, fix that value and try to find a solution
-            for z in 0..sudoku[i][j].len() {
-                // If there is a signal of stop, kill the goroutine
-                let res = stopChannelReceiver.lock().unwrap().try_recv();
-                // If the operation turns out not to be ready, retry
-                if let Err(e) = res {
-                    if e.is_empty() {
-                        // Copy the sudoku matrix
-                        let mut sudokuCopy = copySudokuMatrix(&sudoku);
-                        // Fix the value
-                        sudokuCopy[i][j] = vec![sudoku[i][j][z]];
-                        // Delete the contraints, in order to converge faster to a solution
-                        sudokuCopy = sudokuConstraintsElimination(sudokuCopy);
-                        // Before starting the new goroutine, check if the obtained matrix is correct
-                        let receiverClone = stopChannelReceiver.clone();
-                        let senderClone = stopChannelSender.clone();
-                        if CheckSudokuMethods::checkBaseSudokuCorrectness(copySudokuMatrix(&sudokuCopy)) {
-                            // Start a new thread that executes searchSolution with the new matrix
-                            scope.spawn(move |_var| {searchSolution(sudokuCopy, &solutionChannel, senderClone, receiverClone)});
-                        }
-                    }
-                } else if res.unwrap() == true {
-                    // Send a stop signal to the running threads, because the signal received has been consumed.
-                    // If there isn't a running thread, an error will be returned because the channel receiver will be destroyed. Since this is not a problem, the case isn't handled.
-                    stopChannelSender.lock().unwrap().send(true);
-                    return;
-                }
-            }
-        });
+}
+
+impl solutionsIterator {
+    // Stop the search and wait the end of its threads, returning the error of the search if there is one
+    fn stop(&mut self) -> Result<(), sudokuError> {
+        // Closing both channels wakes up the threads waiting to send a solution
+        self.stopChannel = None;
+        self.solutionChannel = None;
+        if let Some(searchThread) = self.searchThread.take() {
+            // A panic of the search is returned as an error, because this is also called while the iterator is dropped
+            searchThread.join().map_err(|_error| sudokuError::ThreadPanicked)??;
+        }
+        return Ok(());
     }
 }
 
-// Copy the sudoku matrix, copying every integer value
-fn copySudokuMatrix(sudoku: &[[Vec<i8>; 9]; 9]) -> [[Vec<i8>; 9]; 9] {
-    let mut sudokuCopy: [[Vec<i8>; 9]; 9] = Default::default();
+impl Drop for solutionsIterator {
+    fn drop(&mut self) {
+        // The remaining solutions aren't needed, so the error of the search can be ignored
+        let _ = self.stop();
+    }
+}
+
+// Return an iterator over the solutions of the sudoku, which stops after "limit" solutions if it is given
+pub fn findSolutions(sudoku: [[candidateSet; 9]; 9], limit: Option<usize>) -> Result<solutionsIterator, sudokuError> {
+    let (solutionChannelSender, solutionChannelReceiver) = bounded(0);
+    let (stopChannelSender, stopChannelReceiver) = bounded::<()>(0);
+    // Without solutions the channel is closed immediately, because the sender is destroyed
+    let searchThread = prepareSearch(sudoku)?.map(|sudokuTable| {
+        thread::spawn(move || searchSolution(sudokuTable, &solutionChannelSender, &stopChannelReceiver))
+    });
+    return Ok(solutionsIterator{solutionChannel: Some(solutionChannelReceiver), stopChannel: Some(stopChannelSender),
+        searchThread, remaining: limit});
+}
+
+// Delete the constraints of the sudoku before starting a search, so the definitive values are checked too.
+// Return None if the sudoku breaks the rules, because in that case there aren't solutions
+fn prepareSearch(sudoku: [[candidateSet; 9]; 9]) -> Result<Option<[[candidateSet; 9]; 9]>, sudokuError> {
+    let sudokuTable = sudokuConstraintsElimination(sudoku)?;
+    if !CheckSudokuMethods::checkBaseSudokuCorrectness(sudokuTable)? {
+        return Ok(None);
+    }
+    return Ok(Some(sudokuTable));
+}
+
+// Solutions collected by a search, completed is true if every branch has been analyzed
+struct searchResult {
+    solutions: Vec<[[candidateSet; 9]; 9]>,
+    completed: bool
+}
+
+// Start "searchSolution" as a goroutine and collect at most "limit" solutions, stopping the search when they are found or when
+// the time is over
+fn runSearch(sudokuTable: [[candidateSet; 9]; 9], limit: usize, timeLimit: Option<Duration>) -> Result<searchResult, sudokuError> {
+    let (solutionChannelSender, solutionChannelReceiver) = unbounded();
+    // Create a channel to send a stop signal to the active threads: nothing is sent, the signal is the closure of the channel
+    let (stopChannelSender, stopChannelReceiver) = bounded::<()>(0);
+    let deadline = timeLimit.map(|timeLimit| Instant::now() + timeLimit);
+
+    return crossbeam::scope(|scope| {
+        let stopChannelReceiverRef = &stopChannelReceiver;
+        let searchThread = scope.spawn(move |_var| {searchSolution(sudokuTable, &solutionChannelSender, stopChannelReceiverRef)});
+        // Wait the solutions. The channel is closed when the search thread ends, because it owns the only sender
+        let mut solutions = Vec::new();
+        while solutions.len() < limit {
+            let received = match deadline {
+                Some(deadline) => solutionChannelReceiver.recv_timeout(deadline.saturating_duration_since(Instant::now())).ok(),
+                None => solutionChannelReceiver.recv().ok()
+            };
+            match received {
+                Some(solution) => solutions.push(solution),
+                None => break
+            }
+        }
+        // Send a stop signal to the running threads, because there are enough solutions or the time is over
+        drop(stopChannelSender);
+        // Wait the end of every thread, so every branch of the search is either completed or stopped
+        let completed = searchThread.join().unwrap()?;
+        return Ok(searchResult{solutions, completed});
+    }).unwrap();
+}
+
+// Number of levels of the search tree in which every branch is analyzed by a new thread. The deeper levels are
+// analyzed sequentially by the thread of their first ancestor, otherwise the number of threads would grow exponentially
+const PARALLEL_DEPTH: usize = 3;
+
+// Search the solutions of the sudoku, parallelizing the first levels of the search tree. Every solution found is sent in the solution channel.
+// Return true if every branch has been analyzed, false if the search has been stopped by the closure of the stop channel
+pub fn searchSolution(sudoku: [[candidateSet; 9]; 9], solutionChannel: &Sender<[[candidateSet; 9]; 9]>, stopChannel: &Receiver<()>) -> Result<bool, sudokuError> {
+    return searchBranch(sudoku, 0, solutionChannel, stopChannel);
+}
+
+// Recursive function, called as a subroutine in order to parallelize the search
+fn searchBranch(sudoku: [[candidateSet; 9]; 9], depth: usize, solutionChannel: &Sender<[[candidateSet; 9]; 9]>, stopChannel: &Receiver<()>) -> Result<bool, sudokuError> {
+    // If there is a signal of stop, kill the thread
+    if stopRequested(stopChannel) {
+        return Ok(false);
+    }
+    // Find the cell with the minimum number of possible values (but more than one), to have as few branches as possible
+    let mut position = None;
+    let mut minimumValues = 10;
     for i in 0..9 {
         for j in 0..9 {
-            for z in 0..sudoku[i][j].len() {
-                sudokuCopy[i][j].push(sudoku[i][j][z]);
+            let possibleValues = sudoku[i][j].len();
+            if possibleValues > 1 && possibleValues < minimumValues {
+                position = Some((i, j));
+                minimumValues = possibleValues;
             }
         }
     }
-    return sudokuCopy;
+    // If there isn't such a cell then the sudoku is already a solution, so it can be saved in the solution channel.
+    // If the receiver doesn't need other solutions, the channel will be closed and the search stopped
+    let (i, j) = match position {
+        Some(position) => position,
+        None => return Ok(solutionChannel.send(sudoku).is_ok())
+    };
+    return crossbeam::scope(|scope| {
+        let mut threads = Vec::new();
+        let mut completed = true;
+        // For every possible value of the found cell, copy the matrix, fix that value and try to find a solution
+        for value in sudoku[i][j] {
+            // If there is a signal of stop, don't analyze other branches
+            if stopRequested(stopChannel) {
+                completed = false;
+                break;
+            }
+            // Copy the sudoku matrix and fix the value
+            let mut sudokuCopy = sudoku;
+            sudokuCopy[i][j] = candidateSet::single(value);
+            // Delete the contraints, in order to converge faster to a solution
+            sudokuCopy = sudokuConstraintsElimination(sudokuCopy)?;
+            // Before analyzing the new branch, check if the obtained matrix is correct, otherwise this branch doesn't have solutions
+            if CheckSudokuMethods::checkBaseSudokuCorrectness(sudokuCopy)? {
+                if depth < PARALLEL_DEPTH {
+                    // Start a new thread that executes searchBranch with the new matrix
+                    threads.push(scope.spawn(move |_var| {searchBranch(sudokuCopy, depth + 1, solutionChannel, stopChannel)}));
+                } else {
+                    completed &= searchBranch(sudokuCopy, depth + 1, solutionChannel, stopChannel)?;
+                }
+            }
+        }
+        // Wait the started threads, the search is completed only if every thread has completed its own
+        for thread in threads {
+            completed &= thread.join().unwrap()?;
+        }
+        return Ok(completed);
+    }).unwrap();
 }
 
-fn countPossibilities(sudoku: [[Vec<i8>; 9]; 9]) -> BigInt {
+// Check if the stop channel has been closed
+fn stopRequested(stopChannel: &Receiver<()>) -> bool {
+    return stopChannel.try_recv() == Err(TryRecvError::Disconnected);
+}
+
+// Count the number of combinations that brute force should analyze without any constraint elimination
+pub fn countPossibilities(sudoku: [[candidateSet; 9]; 9]) -> BigInt {
     let mut total: BigInt = One::one();
     for i in 0..9 {
         for j in 0..9 {
@@ -118,4 +262,64 @@ fn countPossibilities(sudoku: [[Vec<i8>; 9]; 9]) -> BigInt {
         }
     }
     return total;
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Solution of the sudoku of the Wikipedia page about sudokus
+    const SOLUTION: &str = "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+
+    // Solution without the four cells of a rectangle, whose two values can be exchanged
+    const MULTIPLE_SOLUTIONS: &str = "534..8912672195348198342567859..1423426853791713924856961537284287419635345286179";
+    // The definitive values of the first row force a 9 in the last cell, which is already in its column
+    const NO_SOLUTION: &str = "12345678.........9...............................................................";
+
+    // Return the sudoku matrix described by the given string
+    fn matrixOf(description: &str) -> [[candidateSet; 9]; 9] {
+        return sudoku::fromString(description.to_string()).unwrap().sudokuMatrix;
+    }
+
+    // Return a sudokuIOManager that contains the sudoku described by the given string
+    fn managerOf(description: &str) -> Arc<RwLock<sudokuIOManager>> {
+        return Arc::new(RwLock::new(sudokuIOManager::new(sudoku::fromString(description.to_string()).unwrap())));
+    }
+
+    #[test]
+    fn bruteForceReturnsCompleteValidSudoku() {
+        match bruteForceSolving(managerOf(SOLUTION), None).unwrap() {
+            bruteForceResult::Solved(manager) => assert_eq!(manager.GetSudoku().unwrap(), sudoku::fromString(SOLUTION.to_string()).unwrap().sudokuMatrix),
+            _ => panic!("the solution has not been returned")
+        }
+    }
+
+    #[test]
+    fn bruteForceRejectsCompleteInvalidSudoku() {
+        // The first two values are exchanged, so the columns 1 and 2 have repeated values
+        let invalid = format!("35{}", &SOLUTION[2..]);
+        assert!(matches!(bruteForceSolving(managerOf(&invalid), None).unwrap(), bruteForceResult::Unsolvable));
+    }
+
+    #[test]
+    fn countSolutionsStopsAtTheLimit() {
+        assert_eq!(countSolutions(matrixOf(SOLUTION), 2, None).unwrap(), 1);
+        assert_eq!(countSolutions(matrixOf(MULTIPLE_SOLUTIONS), 5, None).unwrap(), 2);
+        assert_eq!(countSolutions(matrixOf(MULTIPLE_SOLUTIONS), 1, None).unwrap(), 1);
+        assert_eq!(countSolutions(matrixOf(MULTIPLE_SOLUTIONS), 0, None).unwrap(), 0);
+    }
+
+    #[test]
+    fn countSolutionsWithoutSolutions() {
+        assert_eq!(countSolutions(matrixOf(NO_SOLUTION), 2, None).unwrap(), 0);
+        let invalid = format!("35{}", &SOLUTION[2..]);
+        assert_eq!(countSolutions(matrixOf(&invalid), 2, None).unwrap(), 0);
+    }
+
+    #[test]
+    fn isUniqueChecksTheNumberOfSolutions() {
+        assert!(isUnique(matrixOf(SOLUTION), None).unwrap());
+        assert!(!isUnique(matrixOf(MULTIPLE_SOLUTIONS), None).unwrap());
+        assert!(!isUnique(matrixOf(NO_SOLUTION), None).unwrap());
+    }
+}
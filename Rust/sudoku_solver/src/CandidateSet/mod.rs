@@ -0,0 +1,189 @@
+use std::fmt;
+
+// Set of the possible values of a cell: the bit n of the mask is set if the value n (from 1 to 9) is possible
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct candidateSet {
+    mask: u16
+}
+
+// Mask with every value from 1 to 9
+const ALL_VALUES: u16 = 0b11_1111_1110;
+
+impl candidateSet {
+    // Set without values
+    pub fn new() -> candidateSet {
+        return candidateSet{mask: 0};
+    }
+
+    // Set with every value from 1 to 9
+    pub fn all() -> candidateSet {
+        return candidateSet{mask: ALL_VALUES};
+    }
+
+    // Set with only the given value
+    pub fn single(value: i8) -> candidateSet {
+        return candidateSet{mask: 1 << value};
+    }
+
+    // Set with the values given in input
+    pub fn fromValues(values: &[i8]) -> candidateSet {
+        let mut set = candidateSet::new();
+        for &value in values {
+            set.insert(value);
+        }
+        return set;
+    }
+
+    // Set with the values of the bits of the mask given in input, the bits that aren't values from 1 to 9 are ignored
+    pub fn fromMask(mask: u16) -> candidateSet {
+        return candidateSet{mask: mask & ALL_VALUES};
+    }
+
+    // Return the mask of the set
+    pub fn mask(&self) -> u16 {
+        return self.mask;
+    }
+
+    // Return the number of values of the set
+    pub fn len(&self) -> usize {
+        return self.mask.count_ones() as usize;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.mask == 0;
+    }
+
+    // Check if the set contains the given value
+    pub fn contains(&self, value: i8) -> bool {
+        return self.mask & (1 << value) != 0;
+    }
+
+    // Add a value to the set
+    pub fn insert(&mut self, value: i8) {
+        self.mask |= 1 << value;
+    }
+
+    // Delete a value from the set
+    pub fn remove(&mut self, value: i8) {
+        self.mask &= !(1 << value);
+    }
+
+    // Return the smallest value of the set, which is the definitive value if the set has only one element
+    pub fn first(&self) -> Option<i8> {
+        if self.mask == 0 {
+            return None;
+        }
+        return Some(self.mask.trailing_zeros() as i8);
+    }
+
+    // Return the values contained in both sets
+    pub fn intersection(&self, other: candidateSet) -> candidateSet {
+        return candidateSet{mask: self.mask & other.mask};
+    }
+
+    // Return the values contained in at least one of the sets
+    pub fn union(&self, other: candidateSet) -> candidateSet {
+        return candidateSet{mask: self.mask | other.mask};
+    }
+
+    // Return the values of this set which aren't contained in the other one
+    pub fn difference(&self, other: candidateSet) -> candidateSet {
+        return candidateSet{mask: self.mask & !other.mask};
+    }
+
+    // Check if every value of this set is contained in the other one
+    pub fn isSubset(&self, other: candidateSet) -> bool {
+        return self.mask & !other.mask == 0;
+    }
+
+    // Return an iterator over the values of the set, in increasing order
+    pub fn iter(&self) -> candidateIterator {
+        return candidateIterator{mask: self.mask};
+    }
+
+    // Return the values of the set in increasing order
+    pub fn values(&self) -> Vec<i8> {
+        return self.iter().collect();
+    }
+}
+
+impl fmt::Display for candidateSet {
+    // Print the values between braces, for example {1,5,7}
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values: Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "{{{}}}", values.join(","))
+    }
+}
+
+impl IntoIterator for candidateSet {
+    type Item = i8;
+    type IntoIter = candidateIterator;
+
+    fn into_iter(self) -> candidateIterator {
+        return self.iter();
+    }
+}
+
+// Iterator over the values of a candidateSet, every value is removed from the mask when it is returned
+pub struct candidateIterator {
+    mask: u16
+}
+
+impl Iterator for candidateIterator {
+    type Item = i8;
+
+    fn next(&mut self) -> Option<i8> {
+        if self.mask == 0 {
+            return None;
+        }
+        let value = self.mask.trailing_zeros() as i8;
+        self.mask &= self.mask - 1;
+        return Some(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setOperations() {
+        // The operations work on the bits of the values, so they must agree with the corresponding operations on the values
+        let first = candidateSet::fromValues(&[1, 2, 5, 9]);
+        let second = candidateSet::fromValues(&[2, 5, 7]);
+        assert_eq!(first.len(), 4);
+        assert!(first.contains(9) && !first.contains(7));
+        assert_eq!(first.intersection(second).values(), vec![2, 5]);
+        assert_eq!(first.union(second).values(), vec![1, 2, 5, 7, 9]);
+        assert_eq!(first.difference(second).values(), vec![1, 9]);
+        assert!(first.intersection(second).isSubset(second));
+        assert!(!first.isSubset(second));
+    }
+
+    #[test]
+    fn insertionAndRemoval() {
+        // The first value is the definitive one when only one value is left, and the set prints its values between braces
+        let mut set = candidateSet::all();
+        assert_eq!(set.len(), 9);
+        for value in 2..10 {
+            set.remove(value);
+        }
+        assert_eq!(set.first(), Some(1));
+        assert_eq!(set, candidateSet::single(1));
+        set.insert(4);
+        assert_eq!(set.to_string(), "{1,4}");
+        set.remove(1);
+        set.remove(4);
+        assert!(set.is_empty());
+        assert_eq!(set.first(), None);
+        assert_eq!(set.to_string(), "{}");
+    }
+
+    #[test]
+    fn maskOutsideValuesIgnored() {
+        // The bit 0 and the bits over 9 aren't values, so they are dropped when building a set from a mask
+        let set = candidateSet::fromMask(0xFFFF);
+        assert_eq!(set, candidateSet::all());
+        assert_eq!(set.mask(), ALL_VALUES);
+    }
+}
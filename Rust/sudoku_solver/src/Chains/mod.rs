@@ -0,0 +1,309 @@
+use std::sync::{Arc, RwLock};
+use std::collections::VecDeque;
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{seesCell, cellName};
+use super::StrongLinks::strongLinkIndex;
+use super::Deductions::{deduction, applyNewDeductions};
+use super::StrategyRunners::repeatUntilStable;
+
+// Maximum number of links of the chains used when the solver doesn't specify a different value
+pub const DEFAULT_CHAIN_LENGTH: usize = 12;
+
+// Kinds of chains: the X-Cycles use only the links of a single value, the alternating inference chains use also the
+// links between the values of the same cell
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum chainKind {
+    XCycle, AlternatingInference
+}
+
+// Candidate of a chain: a possible value of a cell
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct chainNode {
+    pub cell: (usize, usize),
+    pub value: i8
+}
+
+impl chainNode {
+    // Check if the two candidates can't be both true: they are different values of the same cell, or the same value
+    // of two cells that see each other
+    pub fn isWeaklyLinked(&self, other: &chainNode) -> bool {
+        if self.cell == other.cell {
+            return self.value != other.value;
+        }
+        return self.value == other.value && seesCell(self.cell, other.cell);
+    }
+}
+
+impl std::fmt::Display for chainNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return write!(f, "({}){}", self.value, cellName(self.cell.0, self.cell.1));
+    }
+}
+
+// Graph of the candidates of the cells without a definitive value. A strong link means that at least one of the two
+// candidates is true, a weak link that at most one of them is true (so every strong link is also a weak link)
+pub struct chainGraph {
+    pub nodes: Vec<chainNode>,
+    // Values of every cell, also the ones without a node in the graph
+    pub candidates: [[candidateSet; 9]; 9],
+    pub strongLinks: Vec<Vec<usize>>,
+    pub weakLinks: Vec<Vec<usize>>
+}
+
+impl chainGraph {
+    // Build the graph of the given kind of chains, with the candidates of the given values
+    pub fn new(sudokuCopy: &[[candidateSet; 9]; 9], kind: chainKind, values: candidateSet) -> chainGraph {
+        // Position of the node of every candidate, saved in cell*10+value
+        let mut positions = vec![None; 810];
+        let mut nodes = Vec::new();
+        for i in 0..9 {
+            for j in 0..9 {
+                if sudokuCopy[i][j].len() < 2 {
+                    continue;
+                }
+                for value in sudokuCopy[i][j].intersection(values) {
+                    positions[(i*9+j)*10 + value as usize] = Some(nodes.len());
+                    nodes.push(chainNode{cell: (i, j), value});
+                }
+            }
+        }
+        let position = |cell: (usize, usize), value: i8| positions[(cell.0*9+cell.1)*10 + value as usize];
+
+        let mut strongLinks = vec![Vec::new(); nodes.len()];
+        let links = strongLinkIndex::new(sudokuCopy);
+        for value in values {
+            for link in links.linksOfValue(value) {
+                if let (Some(first), Some(second)) = (position(link.first, value), position(link.second, value)) {
+                    strongLinks[first].push(second);
+                    strongLinks[second].push(first);
+                }
+            }
+        }
+        let mut weakLinks = vec![Vec::new(); nodes.len()];
+        for (first, firstNode) in nodes.iter().enumerate() {
+            for (second, secondNode) in nodes.iter().enumerate() {
+                // The links inside a cell are used only by the alternating inference chains
+                if !firstNode.isWeaklyLinked(secondNode) || (kind == chainKind::XCycle && firstNode.cell == secondNode.cell) {
+                    continue;
+                }
+                weakLinks[first].push(second);
+                // A cell with two values is a strong link between them
+                if firstNode.cell == secondNode.cell && sudokuCopy[firstNode.cell.0][firstNode.cell.1].len() == 2 {
+                    strongLinks[first].push(second);
+                }
+            }
+        }
+        for nodeLinks in strongLinks.iter_mut() {
+            nodeLinks.sort_unstable();
+            nodeLinks.dedup();
+        }
+        return chainGraph{nodes, candidates: *sudokuCopy, strongLinks, weakLinks};
+    }
+}
+
+// Find all the X-Cycles with at most the given number of links, until there are no more values to delete
+pub fn findXCycles(ioManager: Arc<RwLock<sudokuIOManager>>, maxLength: usize) -> Result<(), sudokuError> {
+    return findChains(ioManager, chainKind::XCycle, maxLength);
+}
+
+// Find all the alternating inference chains with at most the given number of links, until there are no more values to delete
+pub fn findAlternatingInferenceChains(ioManager: Arc<RwLock<sudokuIOManager>>, maxLength: usize) -> Result<(), sudokuError> {
+    return findChains(ioManager, chainKind::AlternatingInference, maxLength);
+}
+
+// Find all the chains of the given kind with at most the given number of links, until there are no more values to delete
+pub fn findChains(ioManager: Arc<RwLock<sudokuIOManager>>, kind: chainKind, maxLength: usize) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, |manager| findGridChains(manager, kind, maxLength));
+}
+
+// Find the chains of the given kind that start from every candidate and delete the values excluded by them, starting
+// from the shortest chains. Updates signals if there are deleted values
+pub fn findGridChains(ioManager: Arc<RwLock<sudokuIOManager>>, kind: chainKind, maxLength: usize) -> Result<bool, sudokuError> {
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    // The X-Cycles of different values are independent, so they use different graphs
+    let graphs: Vec<chainGraph> = match kind {
+        chainKind::XCycle => candidateSet::all().iter().map(|value| chainGraph::new(&sudokuCopy, kind, candidateSet::single(value))).collect(),
+        chainKind::AlternatingInference => vec![chainGraph::new(&sudokuCopy, kind, candidateSet::all())]
+    };
+    let mut deductions = Vec::new();
+    for graph in graphs.iter() {
+        for start in 0..graph.nodes.len() {
+            deductions.extend(findNodeChains(graph, kind, start, maxLength));
+        }
+    }
+
+    // Every deleted value is explained by the shortest chain that deletes it
+    deductions.sort_by_key(|&(length, _)| length);
+    return applyNewDeductions(&ioManager.read().unwrap(), deductions.into_iter().map(|(_length, deductionVar)| deductionVar).collect());
+}
+
+// Find the shortest chains that start from the given node with a strong link and end with a strong link, and return
+// their deductions with the number of their links. If the start node is false the end node is true, so the candidates
+// that see both of them can be deleted. A chain that ends in the start node places its value, while a chain whose ends
+// are weakly linked is a continuous loop, in which every weak link is also a strong link
+pub fn findNodeChains(graph: &chainGraph, kind: chainKind, start: usize, maxLength: usize) -> Vec<(usize, deduction)> {
+    let technique = if kind == chainKind::XCycle { "X-Cycle" } else { "AIC" };
+    let mut deductions = Vec::new();
+    // Every state is a node with the state of its candidate (true in odd positions), saved with the previous state of the chain
+    let mut previous: Vec<Option<usize>> = vec![None; graph.nodes.len()*2];
+    let mut visited = vec![false; graph.nodes.len()*2];
+    let mut toVisit = VecDeque::new();
+    visited[start*2] = true;
+    toVisit.push_back((start*2, 0));
+    while let Some((state, length)) = toVisit.pop_front() {
+        if length == maxLength {
+            continue;
+        }
+        let (node, isTrue) = (state / 2, state % 2 == 1);
+        // A false candidate makes true its strongly linked candidates, a true candidate makes false its weakly linked ones
+        let nextNodes = if isTrue { &graph.weakLinks[node] } else { &graph.strongLinks[node] };
+        for &next in nextNodes.iter() {
+            let nextState = next*2 + if isTrue { 0 } else { 1 };
+            if visited[nextState] {
+                continue;
+            }
+            visited[nextState] = true;
+            previous[nextState] = Some(state);
+            toVisit.push_back((nextState, length + 1));
+            if !isTrue {
+                let chain = chainStates(&previous, nextState);
+                if let Some(deductionVar) = chainDeduction(graph, technique, &chain) {
+                    deductions.push((length + 1, deductionVar));
+                }
+            }
+        }
+    }
+    return deductions;
+}
+
+// Return the nodes of the chain that ends in the given state, from the start node
+fn chainStates(previous: &[Option<usize>], end: usize) -> Vec<usize> {
+    let mut chain = vec![end / 2];
+    let mut state = end;
+    while let Some(previousState) = previous[state] {
+        chain.push(previousState / 2);
+        state = previousState;
+    }
+    chain.reverse();
+    return chain;
+}
+
+// Return the deduction of a chain that starts and ends with a strong link, if it deletes at least a value
+fn chainDeduction(graph: &chainGraph, technique: &str, chain: &[usize]) -> Option<deduction> {
+    let first = chain[0];
+    let last = chain[chain.len()-1];
+    let firstNode = graph.nodes[first];
+    let lastNode = graph.nodes[last];
+    let mut deleted = Vec::new();
+    // Values deleted from the start cell, which can be without a node in the graph
+    let mut cellValues = candidateSet::new();
+    let description;
+    if first == last {
+        // The start node is true, so its value can be deleted from the cells that see it, and the other values of its
+        // cell can be deleted too (the graphs of the X-Cycles have only the nodes of a single value)
+        deleted = graph.weakLinks[first].iter().cloned().filter(|&node| graph.nodes[node].cell != firstNode.cell).collect();
+        cellValues = graph.candidates[firstNode.cell.0][firstNode.cell.1].difference(candidateSet::single(firstNode.value));
+        description = format!("discontinuous loop {}, {} is {}", chainNotation(graph, chain), cellName(firstNode.cell.0, firstNode.cell.1), firstNode.value);
+    } else if firstNode.isWeaklyLinked(&lastNode) {
+        // Every pair of weakly linked nodes of the loop (the ones in odd positions and the ends) has a true candidate
+        let mut pairs: Vec<(usize, usize)> = (1..chain.len()-1).step_by(2).map(|position| (chain[position], chain[position+1])).collect();
+        pairs.push((last, first));
+        for &(firstPair, secondPair) in pairs.iter() {
+            for &node in graph.weakLinks[firstPair].iter() {
+                if !chain.contains(&node) && !deleted.contains(&node) && graph.nodes[node].isWeaklyLinked(&graph.nodes[secondPair]) {
+                    deleted.push(node);
+                }
+            }
+        }
+        description = format!("continuous loop {}", chainNotation(graph, chain));
+    } else {
+        // One of the ends is true, so the candidates weakly linked to both can be deleted
+        deleted = graph.weakLinks[first].iter().cloned().filter(|&node| node != last && graph.nodes[node].isWeaklyLinked(&lastNode)).collect();
+        description = format!("chain {}", chainNotation(graph, chain));
+    }
+    if deleted.is_empty() && cellValues.is_empty() {
+        return None;
+    }
+    let mut deductionVar = deduction::new(technique, description);
+    for node in deleted {
+        deductionVar.addElimination(graph.nodes[node].cell.0, graph.nodes[node].cell.1, graph.nodes[node].value);
+    }
+    for value in cellValues {
+        deductionVar.addElimination(firstNode.cell.0, firstNode.cell.1, value);
+    }
+    return Some(deductionVar);
+}
+
+// Return the notation of a chain that starts with a strong link, in which "=" is a strong link and "-" a weak link
+pub fn chainNotation(graph: &chainGraph, chain: &[usize]) -> String {
+    let mut notation = graph.nodes[chain[0]].to_string();
+    for (position, &node) in chain.iter().enumerate().skip(1) {
+        notation.push_str(if position % 2 == 1 { "=" } else { "-" });
+        notation.push_str(&graph.nodes[node].to_string());
+    }
+    return notation;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, removeValue, managerOf, eliminationsOf, descriptionsOf};
+
+    #[test]
+    fn xCycleDiscontinuousLoopPlacesValue() {
+        // The value 1 has the strong links r1c1=r1c5 in the row 1, r5c5=r5c2 in the row 5 and r4c1=r1c1 in the column 1,
+        // so if r1c1 isn't 1 then r1c5 is 1, r5c5 isn't, r5c2 is, r4c1 isn't and r1c1 is: r1c1 must be 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(4, 0), (4, 2), (4, 3), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        removeValue(&mut grid, 1, &[(1, 0), (2, 0), (5, 0), (6, 0), (7, 0), (8, 0)]);
+        let graph = chainGraph::new(&grid, chainKind::XCycle, candidateSet::single(1));
+        let start = graph.nodes.iter().position(|node| node.cell == (0, 0)).unwrap();
+        let loops: Vec<(usize, deduction)> = findNodeChains(&graph, chainKind::XCycle, start, DEFAULT_CHAIN_LENGTH).into_iter()
+            .filter(|(_length, deductionVar)| deductionVar.description.starts_with("discontinuous loop")).collect();
+        assert_eq!(loops.len(), 1);
+        assert_eq!(loops[0].0, 5);
+        assert_eq!(loops[0].1.technique, "X-Cycle");
+        assert_eq!(loops[0].1.description, "discontinuous loop (1)r1c1=(1)r1c5-(1)r5c5=(1)r5c2-(1)r4c1=(1)r1c1, r1c1 is 1");
+        // The 1 is deleted from the other cells of the row, of the column and of the box of r1c1, and the other values
+        // are deleted from r1c1
+        let mut expected: Vec<(usize, usize, i8)> = (2..10).map(|value| (0, 0, value)).collect();
+        expected.extend(vec![(0, 4, 1), (1, 1, 1), (1, 2, 1), (2, 1, 1), (2, 2, 1), (3, 0, 1)]);
+        assert_eq!(eliminationsOf(&[loops[0].1.clone()]), expected);
+    }
+
+    #[test]
+    fn xCycleContinuousLoop() {
+        // The strong links r1c1=r1c5, r1c5=r5c5 and r5c5=r5c1 of the value 1 and the weak link r5c1-r1c1 form a loop, so
+        // one of r1c1 and r5c1 is 1 and the rest of the column 1 can't be 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(1, 4), (2, 4), (3, 4), (5, 4), (6, 4), (7, 4), (8, 4)]);
+        removeValue(&mut grid, 1, &[(4, 1), (4, 2), (4, 3), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        let manager = managerOf(grid);
+        assert!(findGridChains(manager.clone(), chainKind::XCycle, DEFAULT_CHAIN_LENGTH).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(descriptionsOf(&deductions), vec!["X-Cycle: continuous loop (1)r1c1=(1)r1c5-(1)r5c5=(1)r5c1"]);
+        assert_eq!(eliminationsOf(&deductions), [1, 2, 3, 5, 6, 7, 8].iter().map(|&i| (i, 0, 1)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn alternatingInferenceChain() {
+        // r1c1 has only 1 and 2, r1c5 only 1 and 3, and r5c1 only 2 and 3: if r1c5 isn't 3 it's 1, so r1c1 is 2 and r5c1
+        // is 3. So one of r1c5 and r5c1 is 3, and r5c5 can't be 3
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(0, 4)]);
+        setValues(&mut grid, &[2, 3], &[(4, 0)]);
+        let manager = managerOf(grid);
+        assert!(!findGridChains(manager.clone(), chainKind::XCycle, DEFAULT_CHAIN_LENGTH).unwrap());
+        assert!(findGridChains(manager.clone(), chainKind::AlternatingInference, DEFAULT_CHAIN_LENGTH).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(descriptionsOf(&deductions), vec!["AIC: chain (3)r1c5=(1)r1c5-(1)r1c1=(2)r1c1-(2)r5c1=(3)r5c1"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(4, 4, 3)]);
+    }
+}
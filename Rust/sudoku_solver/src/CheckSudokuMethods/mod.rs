@@ -1,7 +1,9 @@
 extern crate crossbeam;
-use crossbeam::crossbeam_channel::{Sender, bounded};
-use super::SudokuManager::{sudoku, contains};
+use crossbeam::crossbeam_channel::bounded;
+use super::SudokuManager::sudoku;
+use super::CandidateSet::candidateSet;
 use super::SudokuIOManager::sudokuIOManager;
+use super::SudokuError::sudokuError;
 
 // Check if there are cells without definitive values
 pub fn checkSudokuIsComplete(ioManager: &sudokuIOManager) -> bool {
@@ -16,14 +18,14 @@ pub fn checkSudokuIsComplete(ioManager: &sudokuIOManager) -> bool {
 }
 
 // Create a sudokuIOManager and call the function below
-pub fn checkBaseSudokuCorrectness(sudoku: [[Vec<i8>; 9]; 9]) -> bool {
+pub fn checkBaseSudokuCorrectness(sudoku: [[candidateSet; 9]; 9]) -> Result<bool, sudokuError> {
     let sudokuContainer = sudoku::newContainer(sudoku);
     let ioManager = sudokuIOManager::new(sudokuContainer);
     return checkSudokuCorrectness(&ioManager);
 }
 
 // Check if the cells with definitive values have valid numbers
-pub fn checkSudokuCorrectness(ioManager: &sudokuIOManager) -> bool {
+pub fn checkSudokuCorrectness(ioManager: &sudokuIOManager) -> Result<bool, sudokuError> {
     // Make channels to communicate the correctness
     let (horizontalChannelSender, horizontalChannelReceiver)  = bounded(1);
     let (verticalChannelSender, verticalChannelReceiver)  = bounded(1);
@@ -31,88 +33,88 @@ pub fn checkSudokuCorrectness(ioManager: &sudokuIOManager) -> bool {
 
     crossbeam::scope(|scope| {
         // Start the subroutines to check horizontal, vertical and boxes correctness
-        scope.spawn(move |_var| checkHorizontalCorrectness(ioManager, horizontalChannelSender));
-        scope.spawn(move |_var| checkVerticalCorrectness(ioManager, verticalChannelSender));
-        scope.spawn(move |_var| checkBoxesCorrectness(ioManager, boxesChannelSender));
-    });
+        scope.spawn(move |_var| horizontalChannelSender.send(checkHorizontalCorrectness(ioManager)));
+        scope.spawn(move |_var| verticalChannelSender.send(checkVerticalCorrectness(ioManager)));
+        scope.spawn(move |_var| boxesChannelSender.send(checkBoxesCorrectness(ioManager)));
+    }).unwrap();
 
     // Return the result of the three analysis
-    return horizontalChannelReceiver.recv().unwrap() && verticalChannelReceiver.recv().unwrap() && boxesChannelReceiver.recv().unwrap();
+    return Ok(horizontalChannelReceiver.recv()? && verticalChannelReceiver.recv()? && boxesChannelReceiver.recv()?);
 }
 
 // Check if all rows are correct
-fn checkHorizontalCorrectness(ioManager: &sudokuIOManager, updatesChannel: Sender<bool>) {
+fn checkHorizontalCorrectness(ioManager: &sudokuIOManager) -> bool {
     let mut correct = true;
     let mut i = 0;
     while i < 9 && correct {
         // List of already analysed values
-        let mut valuesList: Vec<i8> = Default::default();
+        let mut valuesList = candidateSet::new();
         let mut j = 0;
         while j < 9 && correct {
             let cellSlice = ioManager.GetSlice(i, j);
             // Only cells with definitive values are analysed
             if cellSlice.len() == 1 {
-                if contains(&valuesList, cellSlice[0]) {
+                if valuesList.contains(cellSlice.first().unwrap()) {
                     correct = false;
                 } else {
                     // Save the value for next checks
-                    valuesList.push(cellSlice[0]);
+                    valuesList.insert(cellSlice.first().unwrap());
                 }
             }
             j += 1;
         }
         i += 1;
     }
-    updatesChannel.send(correct).unwrap();
+    return correct;
 }
 
 // Check if all columns are correct
-fn checkVerticalCorrectness(ioManager: &sudokuIOManager, updatesChannel: Sender<bool>) {
+fn checkVerticalCorrectness(ioManager: &sudokuIOManager) -> bool {
     let mut correct = true;
     let mut j = 0;
     while j < 9 && correct {
         // List of already analysed values
-        let mut valuesList: Vec<i8> = Default::default();
+        let mut valuesList = candidateSet::new();
         let mut i = 0;
         while i < 9 && correct {
             let cellSlice = ioManager.GetSlice(i, j);
             // Only cells with definitive values are analysed
             if cellSlice.len() == 1 {
-                if contains(&valuesList, cellSlice[0]) {
+                if valuesList.contains(cellSlice.first().unwrap()) {
                     correct = false;
                 } else {
                     // Save the value for next checks
-                    valuesList.push(cellSlice[0]);
+                    valuesList.insert(cellSlice.first().unwrap());
                 }
             }
             i += 1;
         }
         j += 1;
     }
-    updatesChannel.send(correct).unwrap();
+    return correct;
 }
 
 // Check if all boxes are correct
-fn checkBoxesCorrectness(ioManager: &sudokuIOManager, updatesChannel: Sender<bool>) {
+fn checkBoxesCorrectness(ioManager: &sudokuIOManager) -> bool {
     let mut correct = true;
     for i in 0..3 {
         for j in 0..3 {
             // List of already analysed values
-            let mut valuesList: Vec<i8> = Default::default();
+            let mut valuesList = candidateSet::new();
             for ib in 0..3 {
                 for jb in 0..3 {
                     let cellSlice = ioManager.GetSlice(i*3+ib, j*3+jb);
                     if cellSlice.len() == 1 {
-                        if contains(&valuesList, cellSlice[0]) {
+                        if valuesList.contains(cellSlice.first().unwrap()) {
                             correct = false;
                         } else {
                             // Save the value for next checks
-                            valuesList.push(cellSlice[0]);
+                            valuesList.insert(cellSlice.first().unwrap());
                         }
                     }
                 }
             }
         }
     }
-    updatesChannel.send(correct).unwrap();
+    return correct;
 }
\ No newline at end of file
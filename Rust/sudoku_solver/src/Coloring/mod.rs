@@ -0,0 +1,428 @@
+use std::sync::{Arc, RwLock};
+use std::collections::VecDeque;
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{seesCell, cellName};
+use super::StrongLinks::{strongLink, strongLinkIndex};
+use super::Chains::{chainKind, chainGraph};
+use super::Deductions::deduction;
+use super::StrategyRunners::{runThreadsUntilStable, repeatUntilStable};
+
+// Group of cells connected by strong links of the same value. The cells are divided in two colors, so that the cells
+// of a link have different colors: all the cells of one color have the value, while the others don't
+pub struct colorCluster {
+    pub colors: [Vec<(usize, usize)>; 2]
+}
+
+impl colorCluster {
+    // Check if a cell of the given color sees the given cell
+    fn colorSees(&self, color: usize, cell: (usize, usize)) -> bool {
+        return self.colors[color].iter().any(|&colored| seesCell(colored, cell));
+    }
+
+    // Return the description of the cluster, with the cells of the two colors
+    fn description(&self) -> String {
+        let colorNames: Vec<String> = self.colors.iter()
+            .map(|cells| cells.iter().map(|&(i, j)| cellName(i, j)).collect::<Vec<String>>().join(" ")).collect();
+        return format!("[{}] / [{}]", colorNames[0], colorNames[1]);
+    }
+}
+
+// Find all the eliminations of the simple coloring (color wrap and color trap), until there are no more values to delete
+pub fn findSimpleColoring(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findColoring(ioManager, true, false);
+}
+
+// Find all the eliminations of the multi-coloring, until there are no more values to delete
+pub fn findMultiColoring(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findColoring(ioManager, false, true);
+}
+
+// Find all the eliminations of the selected coloring strategies, until there are no more values to delete. The clusters
+// are built once for every pass and shared by the two strategies
+pub fn findColoring(ioManager: Arc<RwLock<sudokuIOManager>>, simpleColoring: bool, multiColoring: bool) -> Result<(), sudokuError> {
+    if !simpleColoring && !multiColoring {
+        return Ok(());
+    }
+    // A thread for every value, because the colors of different values are independent
+    return runThreadsUntilStable(ioManager, &candidateSet::all().values(), strongLinkIndex::new, |manager, value, sudokuCopy, links| {
+        return findValueColoring(manager, value, sudokuCopy, links.linksOfValue(value), simpleColoring, multiColoring);
+    });
+}
+
+// Color the strong links of the value and delete the values excluded by the colors, updates signals if there are deleted values
+pub fn findValueColoring(ioManager: Arc<RwLock<sudokuIOManager>>, value: i8, sudokuCopy: &[[candidateSet; 9]; 9], links: &[strongLink], simpleColoring: bool, multiColoring: bool) -> Result<bool, sudokuError> {
+    let clusters = buildClusters(links);
+    let mut deductions = Vec::new();
+    if simpleColoring {
+        for cluster in clusters.iter() {
+            // Color wrap: if two cells of the same color see each other, that color can't have the value
+            for color in 0..2 {
+                let cells = &cluster.colors[color];
+                if cells.iter().any(|&cell| cluster.colorSees(color, cell)) {
+                    let mut wrap = deduction::new("Simple Coloring", format!("value {}, color wrap in {}", value, cluster.description()));
+                    for &(i, j) in cells.iter() {
+                        wrap.addElimination(i, j, value);
+                    }
+                    deductions.push(wrap);
+                }
+            }
+            // Color trap: a cell that sees both colors can't have the value, because one of the colors has it
+            let mut trap = deduction::new("Simple Coloring", format!("value {}, color trap of {}", value, cluster.description()));
+            addTrapEliminations(&mut trap, sudokuCopy, value, |cell| cluster.colorSees(0, cell) && cluster.colorSees(1, cell));
+            if !trap.eliminations.is_empty() {
+                deductions.push(trap);
+            }
+        }
+    }
+    if multiColoring {
+        for (position, first) in clusters.iter().enumerate() {
+            for (otherPosition, second) in clusters.iter().enumerate() {
+                if position == otherPosition {
+                    continue;
+                }
+                for firstColor in 0..2 {
+                    // Multi-coloring wrap: if a color of the first cluster sees both colors of the second cluster, that color can't have the value
+                    let seesFirst = first.colors[firstColor].iter().any(|&cell| second.colorSees(0, cell));
+                    let seesSecond = first.colors[firstColor].iter().any(|&cell| second.colorSees(1, cell));
+                    if seesFirst && seesSecond {
+                        let mut wrap = deduction::new("Multi-Coloring", format!("value {}, color {} of {} sees both colors of {}", value, firstColor + 1, first.description(), second.description()));
+                        for &(i, j) in first.colors[firstColor].iter() {
+                            wrap.addElimination(i, j, value);
+                        }
+                        deductions.push(wrap);
+                    }
+                    // Multi-coloring trap: if a color of the first cluster sees a color of the second one they can't both have the
+                    // value, so one of the opposite colors has it and the cells that see both opposite colors can't have the value
+                    if position < otherPosition {
+                        for secondColor in 0..2 {
+                            if !first.colors[firstColor].iter().any(|&cell| second.colorSees(secondColor, cell)) {
+                                continue;
+                            }
+                            let description = format!("value {}, color {} of {} sees color {} of {}", value, firstColor + 1, first.description(), secondColor + 1, second.description());
+                            let mut trap = deduction::new("Multi-Coloring", description);
+                            addTrapEliminations(&mut trap, sudokuCopy, value, |cell| first.colorSees(1 - firstColor, cell) && second.colorSees(1 - secondColor, cell));
+                            if !trap.eliminations.is_empty() {
+                                deductions.push(trap);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let updates = !deductions.is_empty();
+    let readManager = ioManager.read().unwrap();
+    for deductionVar in deductions {
+        readManager.ApplyDeduction(deductionVar)?;
+    }
+    return Ok(updates);
+}
+
+// Divide the cells of the links in clusters of connected cells, and give to every cell the color opposite to the ones
+// of its linked cells. The clusters that can't be colored in this way are ignored
+pub fn buildClusters(links: &[strongLink]) -> Vec<colorCluster> {
+    let mut clusters = Vec::new();
+    // Color of every cell, if it has already been visited
+    let mut cellColors: [[Option<usize>; 9]; 9] = [[None; 9]; 9];
+    for link in links.iter() {
+        if cellColors[link.first.0][link.first.1].is_some() {
+            continue;
+        }
+        // Visit every cell connected to the first cell of the link
+        let mut cluster = colorCluster{colors: [Vec::new(), Vec::new()]};
+        let mut valid = true;
+        let mut toVisit = vec![(link.first, 0)];
+        cellColors[link.first.0][link.first.1] = Some(0);
+        while let Some((cell, color)) = toVisit.pop() {
+            cluster.colors[color].push(cell);
+            for linked in links.iter().filter(|linked| linked.contains(cell)) {
+                let other = linked.other(cell);
+                match cellColors[other.0][other.1] {
+                    None => {
+                        cellColors[other.0][other.1] = Some(1 - color);
+                        toVisit.push((other, 1 - color));
+                    }
+                    Some(otherColor) => valid = valid && otherColor != color
+                }
+            }
+        }
+        if valid {
+            clusters.push(cluster);
+        }
+    }
+    return clusters;
+}
+
+// Add to the deduction the deletion of the value from every cell that satisfies the given condition
+fn addTrapEliminations<F: Fn((usize, usize)) -> bool>(deductionVar: &mut deduction, sudokuCopy: &[[candidateSet; 9]; 9], value: i8, condition: F) {
+    for i in 0..9 {
+        for j in 0..9 {
+            if sudokuCopy[i][j].len() > 1 && sudokuCopy[i][j].contains(value) && condition((i, j)) {
+                deductionVar.addElimination(i, j, value);
+            }
+        }
+    }
+}
+
+// Find all the eliminations of the 3D Medusa, until there are no more values to delete
+pub fn findMedusa(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, findGridMedusa);
+}
+
+// Color the candidates of every value connected by strong links, both between cells (of the same value) and inside the
+// cells with two values, and delete the values excluded by the colors. Updates signals if there are deleted values
+pub fn findGridMedusa(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    let graph = chainGraph::new(&sudokuCopy, chainKind::AlternatingInference, candidateSet::all());
+    let mut visited = vec![false; graph.nodes.len()];
+    let mut deductions = Vec::new();
+    for start in 0..graph.nodes.len() {
+        if visited[start] || graph.strongLinks[start].is_empty() {
+            continue;
+        }
+        // Color every candidate connected to the start candidate, with the color opposite to the ones of its linked candidates
+        let mut colors: Vec<Option<usize>> = vec![None; graph.nodes.len()];
+        let mut valid = true;
+        let mut toVisit = VecDeque::new();
+        colors[start] = Some(0);
+        visited[start] = true;
+        toVisit.push_back(start);
+        while let Some(node) = toVisit.pop_front() {
+            let color = colors[node].unwrap();
+            for &linked in graph.strongLinks[node].iter() {
+                match colors[linked] {
+                    None => {
+                        colors[linked] = Some(1 - color);
+                        visited[linked] = true;
+                        toVisit.push_back(linked);
+                    }
+                    Some(linkedColor) => valid = valid && linkedColor != color
+                }
+            }
+        }
+        if valid {
+            deductions.extend(medusaDeductions(&graph, &sudokuCopy, &colors));
+        }
+    }
+    let updates = !deductions.is_empty();
+    let readManager = ioManager.read().unwrap();
+    for deductionVar in deductions {
+        readManager.ApplyDeduction(deductionVar)?;
+    }
+    return Ok(updates);
+}
+
+// Return the deductions of the six rules of the 3D Medusa for the given colors of the candidates of a cluster
+fn medusaDeductions(graph: &chainGraph, sudokuCopy: &[[candidateSet; 9]; 9], colors: &[Option<usize>]) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    let colorNodes: Vec<Vec<usize>> = (0..2).map(|color| (0..graph.nodes.len()).filter(|&node| colors[node] == Some(color)).collect()).collect();
+    let clusterName = medusaDescription(graph, &colorNodes);
+    let colorSees = |color: usize, node: usize| graph.weakLinks[node].iter().any(|&linked| colors[linked] == Some(color));
+
+    // A color is false if two of its candidates are in the same cell (rule 1) or are the same value in the same unit
+    // (rule 2), or if it would delete every value of a cell (rule 6). Then every candidate of that color can be deleted
+    for color in 0..2 {
+        let mut rule = None;
+        for &node in colorNodes[color].iter() {
+            for &linked in graph.weakLinks[node].iter().filter(|&&linked| colors[linked] == Some(color)) {
+                rule = Some(if graph.nodes[node].cell == graph.nodes[linked].cell { 1 } else { 2 });
+            }
+        }
+        if rule.is_none() {
+            for i in 0..9 {
+                for j in 0..9 {
+                    let cellNodes: Vec<usize> = (0..graph.nodes.len()).filter(|&node| graph.nodes[node].cell == (i, j)).collect();
+                    if sudokuCopy[i][j].len() > 1 && cellNodes.iter().all(|&node| colors[node].is_none() && colorSees(color, node)) {
+                        rule = Some(6);
+                    }
+                }
+            }
+        }
+        if let Some(rule) = rule {
+            let mut wrap = deduction::new("3D Medusa", format!("rule {}, color {} is false in {}", rule, color + 1, clusterName));
+            for &node in colorNodes[color].iter() {
+                wrap.addElimination(graph.nodes[node].cell.0, graph.nodes[node].cell.1, graph.nodes[node].value);
+            }
+            return vec![wrap];
+        }
+    }
+
+    // An uncolored candidate that sees both colors can be deleted, because one of the colors is true. Both colors can
+    // be in its cell (rule 3), in its unit with the same value (rule 4), or one in its cell and one in its unit (rule 5)
+    let mut traps: Vec<deduction> = (3..6).map(|rule| deduction::new("3D Medusa", format!("rule {}, candidates that see both colors of {}", rule, clusterName))).collect();
+    for node in (0..graph.nodes.len()).filter(|&node| colors[node].is_none() && colorSees(0, node) && colorSees(1, node)) {
+        let cellColors = |color: usize| graph.weakLinks[node].iter().any(|&linked| colors[linked] == Some(color) && graph.nodes[linked].cell == graph.nodes[node].cell);
+        let unitColors = |color: usize| graph.weakLinks[node].iter().any(|&linked| colors[linked] == Some(color) && graph.nodes[linked].cell != graph.nodes[node].cell);
+        let rule = if cellColors(0) && cellColors(1) {
+            3
+        } else if unitColors(0) && unitColors(1) {
+            4
+        } else {
+            5
+        };
+        traps[rule - 3].addElimination(graph.nodes[node].cell.0, graph.nodes[node].cell.1, graph.nodes[node].value);
+    }
+    deductions.extend(traps.into_iter().filter(|trap| !trap.eliminations.is_empty()));
+    return deductions;
+}
+
+// Return the description of the cluster of the 3D Medusa, with the candidates of the two colors
+fn medusaDescription(graph: &chainGraph, colorNodes: &[Vec<usize>]) -> String {
+    let colorNames: Vec<String> = colorNodes.iter()
+        .map(|nodes| nodes.iter().map(|&node| graph.nodes[node].to_string()).collect::<Vec<String>>().join(" ")).collect();
+    return format!("[{}] / [{}]", colorNames[0], colorNames[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, removeValue, managerOf, eliminationsOf, techniquesOf};
+
+    // Return the deductions of the coloring of the given value, with the selected strategies
+    fn coloringOf(grid: [[candidateSet; 9]; 9], value: i8, simpleColoring: bool, multiColoring: bool) -> Vec<deduction> {
+        let manager = managerOf(grid);
+        let links = strongLinkIndex::new(&grid);
+        findValueColoring(manager.clone(), value, &grid, links.linksOfValue(value), simpleColoring, multiColoring).unwrap();
+        return manager.read().unwrap().GetDeductions();
+    }
+
+    #[test]
+    fn simpleColoringWrap() {
+        // The strong links r1c1=r1c2 and r1c2=r2c2 of the value 1 give the same color to r1c1 and r2c2, which see each
+        // other. The cells of the box with the value see both colors, so they are deleted also by a color trap
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 2), (0, 3), (0, 4), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1)]);
+        let deductions = coloringOf(grid, 1, true, false);
+        assert_eq!(techniquesOf(&deductions), vec!["Simple Coloring", "Simple Coloring"]);
+        assert_eq!(deductions[0].description, "value 1, color wrap in [r1c1 r2c2] / [r1c2]");
+        assert_eq!(eliminationsOf(&deductions[..1]), vec![(0, 0, 1), (1, 1, 1)]);
+        assert_eq!(eliminationsOf(&deductions[1..]), vec![(0, 0, 1), (1, 0, 1), (1, 1, 1), (1, 2, 1), (2, 0, 1), (2, 2, 1)]);
+    }
+
+    #[test]
+    fn simpleColoringTrap() {
+        // The strong links r1c1=r1c5, r1c5=r5c5 and r5c5=r5c1 of the value 1 give different colors to r1c1 and r5c1, so
+        // the rest of the column 1 can't be 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(1, 4), (2, 4), (3, 4), (5, 4), (6, 4), (7, 4), (8, 4)]);
+        removeValue(&mut grid, 1, &[(4, 1), (4, 2), (4, 3), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        let deductions = coloringOf(grid, 1, true, true);
+        assert_eq!(techniquesOf(&deductions), vec!["Simple Coloring"]);
+        assert_eq!(eliminationsOf(&deductions), [1, 2, 3, 5, 6, 7, 8].iter().map(|&i| (i, 0, 1)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn multiColoringTrap() {
+        // The clusters r1c1=r1c5 and r5c1=r5c4 of the value 1 have r1c1 and r5c1 in the same column, so r1c5 or r5c4 is 1
+        // and the cells that see both can't be 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(4, 1), (4, 2), (4, 4), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        assert!(coloringOf(grid, 1, true, false).is_empty());
+        let deductions = coloringOf(grid, 1, false, true);
+        assert_eq!(techniquesOf(&deductions), vec!["Multi-Coloring"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(1, 3, 1), (2, 3, 1), (3, 4, 1), (5, 4, 1)]);
+    }
+
+    // Return the deductions of the 3D Medusa
+    fn medusaOf(grid: [[candidateSet; 9]; 9]) -> Vec<deduction> {
+        let manager = managerOf(grid);
+        findGridMedusa(manager.clone()).unwrap();
+        return manager.read().unwrap().GetDeductions();
+    }
+
+    #[test]
+    fn medusaRule1() {
+        // r1c1 has only 1 and 2, r5c1 only 2 and 3, and the strong links are r1c1=r1c5 of 1, r1c1=r5c1 of 2, r5c1=r5c5 and
+        // r5c5=r1c5 of 3. So (1)r1c5 and (3)r1c5 have the color of (2)r1c1, which is false because r1c5 can't have two values
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[2, 3], &[(4, 0)]);
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 2, &[(1, 0), (2, 0), (3, 0), (5, 0), (6, 0), (7, 0), (8, 0)]);
+        removeValue(&mut grid, 3, &[(4, 1), (4, 2), (4, 3), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        removeValue(&mut grid, 3, &[(1, 4), (2, 4), (3, 4), (5, 4), (6, 4), (7, 4), (8, 4)]);
+        let deductions = medusaOf(grid);
+        assert_eq!(techniquesOf(&deductions), vec!["3D Medusa"]);
+        assert_eq!(deductions[0].description, "rule 1, color 2 is false in [(1)r1c1 (2)r5c1 (3)r5c5] / [(2)r1c1 (1)r1c5 (3)r1c5 (3)r5c1]");
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 0, 2), (0, 4, 1), (0, 4, 3), (4, 0, 3)]);
+    }
+
+    #[test]
+    fn medusaRule2() {
+        // The strong links r1c1=r1c2 and r1c2=r2c2 of the value 1 give the same color to r1c1 and r2c2, which are in the same box
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 2), (0, 3), (0, 4), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1)]);
+        let deductions = medusaOf(grid);
+        assert_eq!(techniquesOf(&deductions), vec!["3D Medusa"]);
+        assert_eq!(deductions[0].description, "rule 2, color 1 is false in [(1)r1c1 (1)r2c2] / [(1)r1c2]");
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 0, 1), (1, 1, 1)]);
+    }
+
+    #[test]
+    fn medusaRule3() {
+        // r1c1 has only 1 and 2, which in the row 1 are possible only in r1c1 and r1c5: so r1c5 has the two colors of r1c1
+        // exchanged, and it can't have other values
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        let otherCells = [(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)];
+        removeValue(&mut grid, 1, &otherCells);
+        removeValue(&mut grid, 2, &otherCells);
+        let deductions = medusaOf(grid);
+        assert_eq!(techniquesOf(&deductions), vec!["3D Medusa"]);
+        assert!(deductions[0].description.starts_with("rule 3,"));
+        assert_eq!(eliminationsOf(&deductions), (3..10).map(|value| (0, 4, value)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn medusaRule4() {
+        // The strong links r1c1=r1c5, r1c5=r5c5 and r5c5=r5c1 of the value 1 give different colors to r1c1 and r5c1, so
+        // the rest of the column 1 can't be 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 1, &[(1, 4), (2, 4), (3, 4), (5, 4), (6, 4), (7, 4), (8, 4)]);
+        removeValue(&mut grid, 1, &[(4, 1), (4, 2), (4, 3), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        let deductions = medusaOf(grid);
+        assert_eq!(techniquesOf(&deductions), vec!["3D Medusa"]);
+        assert!(deductions[0].description.starts_with("rule 4,"));
+        assert_eq!(eliminationsOf(&deductions), [1, 2, 3, 5, 6, 7, 8].iter().map(|&i| (i, 0, 1)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn medusaRule5() {
+        // r1c1 has only 1 and 2, r5c1 only 2 and 5, and the strong links are r1c1=r1c5 of 1, r1c1=r5c1 of 2 and r5c1=r5c5
+        // of 5. So (1)r1c5 has the color of (5)r5c1, opposite to the one of (5)r5c5: r1c5 can't be 5 and r5c5 can't be 1
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[2, 5], &[(4, 0)]);
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        removeValue(&mut grid, 2, &[(1, 0), (2, 0), (3, 0), (5, 0), (6, 0), (7, 0), (8, 0)]);
+        removeValue(&mut grid, 5, &[(4, 1), (4, 2), (4, 3), (4, 5), (4, 6), (4, 7), (4, 8)]);
+        let deductions = medusaOf(grid);
+        assert_eq!(techniquesOf(&deductions), vec!["3D Medusa"]);
+        assert!(deductions[0].description.starts_with("rule 5,"));
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 4, 5), (4, 4, 1)]);
+    }
+    #[test]
+    fn medusaRule6() {
+        // r1c1 has only 1 and 2, r5c1 only 1 and 3, and the strong links are r1c1=r5c1 of 1 and r5c1=r5c9 of 3. So (2)r1c1
+        // and (3)r5c9 have the same color, which is false because it would delete both the values of r1c9
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(4, 0)]);
+        setValues(&mut grid, &[2, 3], &[(0, 8)]);
+        removeValue(&mut grid, 1, &[(1, 0), (2, 0), (3, 0), (5, 0), (6, 0), (7, 0), (8, 0)]);
+        removeValue(&mut grid, 3, &[(4, 1), (4, 2), (4, 3), (4, 4), (4, 5), (4, 6), (4, 7)]);
+        let deductions = medusaOf(grid);
+        assert_eq!(techniquesOf(&deductions), vec!["3D Medusa"]);
+        assert_eq!(deductions[0].description, "rule 6, color 2 is false in [(1)r1c1 (3)r5c1] / [(2)r1c1 (1)r5c1 (3)r5c9]");
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 0, 2), (4, 0, 1), (4, 8, 3)]);
+    }
+}
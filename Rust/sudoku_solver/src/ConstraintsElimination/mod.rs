@@ -1,22 +1,21 @@
 extern crate crossbeam;
-use crossbeam::crossbeam_channel::{Sender, bounded};
+use crossbeam::crossbeam_channel::bounded;
 use std::sync::{Arc, RwLock, Mutex};
-use std::time::SystemTime;
-use super::SudokuIOManager::{sudokuIOManager, Run};
+use super::SudokuIOManager::sudokuIOManager;
 use super::SudokuManager::sudoku;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
 
 // Create a sudokuIOManager and call the function below
-pub fn sudokuConstraintsElimination(sudoku: [[Vec<i8>; 9]; 9]) -> [[Vec<i8>; 9]; 9] {
+pub fn sudokuConstraintsElimination(sudoku: [[candidateSet; 9]; 9]) -> Result<[[candidateSet; 9]; 9], sudokuError> {
     let sudokuContainer = sudoku::newContainer(sudoku);
     let ioManager = Arc::new(RwLock::new(sudokuIOManager::new(sudokuContainer)));
-    Run(ioManager.clone());
-    constraintsElimination(ioManager.clone());
+    constraintsElimination(ioManager.clone())?;
     return ioManager.read().unwrap().GetSudoku();
 }
 
 // Delete all the horizontal, vertical and boxes invalid constraints
-pub fn constraintsElimination(ioManager: Arc<RwLock<sudokuIOManager>>) {
-    let start = SystemTime::now();
+pub fn constraintsElimination(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
     // Booleans to check if there are deleted values in the following threads
     let mut horizontalUpdates = true;
     let mut verticalUpdates = true;
@@ -33,29 +32,31 @@ pub fn constraintsElimination(ioManager: Arc<RwLock<sudokuIOManager>>) {
 
     // While there is at least one thread that updates one or more values, the three threads must be executed again
     while horizontalUpdates || verticalUpdates || boxesUpdates {
+        // The threads must analyze the sudoku obtained after the deletions of the previous iteration
+        ioManager.read().unwrap().WaitDeletions()?;
         crossbeam::scope(|scope| {
             // Start constraint elimination threads
             let manager = ioManager.clone();
             let channelSenderClone = horizontalChannelSender.clone();
-            scope.spawn(move |_var| horizontalConstraintElimination(manager, channelSenderClone));
+            scope.spawn(move |_var| channelSenderClone.lock().unwrap().send(horizontalConstraintElimination(manager)));
             let manager = ioManager.clone();
             let channelSenderClone = verticalChannelSender.clone();
-            scope.spawn(move |_var| verticalConstraintElimination(manager, channelSenderClone));
+            scope.spawn(move |_var| channelSenderClone.lock().unwrap().send(verticalConstraintElimination(manager)));
             let manager = ioManager.clone();
             let channelSenderClone = boxesChannelSender.clone();
-            scope.spawn(move |_var| boxesConstraintElimination(manager, channelSenderClone));
-        });
+            scope.spawn(move |_var| channelSenderClone.lock().unwrap().send(boxesConstraintElimination(manager)));
+        }).unwrap();
 
         // Save the response of every channel in the respective boolean variable
-        horizontalUpdates = horizontalChannelReceiver.recv().unwrap();
-        verticalUpdates = verticalChannelReceiver.recv().unwrap();
-        boxesUpdates = boxesChannelReceiver.recv().unwrap();
+        horizontalUpdates = horizontalChannelReceiver.recv()??;
+        verticalUpdates = verticalChannelReceiver.recv()??;
+        boxesUpdates = boxesChannelReceiver.recv()??;
     }
-    println!("ConstraintsElimination time: {}", (SystemTime::now().duration_since(start).expect("Time")).as_micros());
+    return Ok(());
 }
 
 // Delete horizontal constraints, updates signals if there are deleted values
-pub fn horizontalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, updatesChannel: Arc<Mutex<Sender<bool>>>) {
+pub fn horizontalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
     let mut updates = false;
     for i in 0..9 {
         for j in 0..9 {
@@ -66,12 +67,12 @@ pub fn horizontalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>,
             drop(readManager);
             // If the slice has a definitive value then delete the occurences of the same value in the row
             if cellSlice.len() == 1 {
-                let cellValue = cellSlice[0];
+                let cellValue = cellSlice.first().unwrap();
                 // Delete the value for the previous cells without definitive values
                 for z in 0..j {
                     let readManager = ioManager.read().unwrap();
                     if readManager.GetSlice(i, z).len() > 1 && readManager.CheckNumber(i, z, cellValue) {
-                        readManager.DeleteNumber(i, z, cellValue);
+                        readManager.DeleteNumber(i, z, cellValue)?;
                         updates = true;
                     }
                 }
@@ -79,18 +80,18 @@ pub fn horizontalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>,
                 for z in j+1..9 {
                     let readManager = ioManager.read().unwrap();
                     if readManager.GetSlice(i, z).len() > 1 && readManager.CheckNumber(i, z, cellValue) {
-                        readManager.DeleteNumber(i, z, cellValue);
+                        readManager.DeleteNumber(i, z, cellValue)?;
                         updates = true;
                     }
                 }
             }
         }
     }
-    updatesChannel.lock().unwrap().send(updates);
+    return Ok(updates);
 }
 
 // Delete vertical constraints, updates signals if there are deleted values
-pub fn verticalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, updatesChannel: Arc<Mutex<Sender<bool>>>) {
+pub fn verticalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
     let mut updates = false;
     for j in 0..9 {
         for i in 0..9 {
@@ -101,12 +102,12 @@ pub fn verticalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, up
             drop(readManager);
             // If the slice has a definitive value then delete the occurences of the same value in the row
             if cellSlice.len() == 1 {
-                let cellValue = cellSlice[0];
+                let cellValue = cellSlice.first().unwrap();
                 // Delete the value for the previous cells without definitive values
                 for z in 0..i {
                     let readManager = ioManager.read().unwrap();
                     if readManager.GetSlice(z, j).len() > 1 && readManager.CheckNumber(z, j, cellValue) {
-                        readManager.DeleteNumber(z, j, cellValue);
+                        readManager.DeleteNumber(z, j, cellValue)?;
                         updates = true;
                     }
                 }
@@ -114,18 +115,18 @@ pub fn verticalConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, up
                 for z in i+1..9 {
                     let readManager = ioManager.read().unwrap();
                     if readManager.GetSlice(z, j).len() > 1 && readManager.CheckNumber(z, j, cellValue) {
-                        readManager.DeleteNumber(z, j, cellValue);
+                        readManager.DeleteNumber(z, j, cellValue)?;
                         updates = true;
                     }
                 }
             }
         }
     }
-    updatesChannel.lock().unwrap().send(updates);
+    return Ok(updates);
 }
 
 // Delete box constraints, updates signals if there are deleted values
-pub fn boxesConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, updatesChannel: Arc<Mutex<Sender<bool>>>) {
+pub fn boxesConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
     let mut updates = false;
     for i in 0..9 {
         for j in 0..9 {
@@ -136,7 +137,7 @@ pub fn boxesConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, updat
             drop(readManager);
             // If the slice has a definitive value then delete the occurences of the same value in the box
             if cellSlice.len() == 1 {
-                let cellValue = cellSlice[0];
+                let cellValue = cellSlice.first().unwrap();
                 // Get the position of the analyzed box among the other boxes
                 let boxRowPosition: i8 = i/3;
                 let boxColumnPosition: i8 = j/3;
@@ -147,7 +148,7 @@ pub fn boxesConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, updat
 						// original cell is excluded automatically because it has a definitive value)
                         let readManager = ioManager.read().unwrap();
                         if readManager.GetSlice(ib, jb).len() > 1 && readManager.CheckNumber(ib, jb, cellValue) {
-                            readManager.DeleteNumber(ib, jb, cellValue);
+                            readManager.DeleteNumber(ib, jb, cellValue)?;
                             updates = true;
                         }
                     }
@@ -155,5 +156,5 @@ pub fn boxesConstraintElimination(ioManager: Arc<RwLock<sudokuIOManager>>, updat
             }
         }
     }
-    updatesChannel.lock().unwrap().send(updates);
+    return Ok(updates);
 }
\ No newline at end of file
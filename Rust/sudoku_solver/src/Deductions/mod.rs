@@ -0,0 +1,73 @@
+use std::fmt;
+use std::collections::HashSet;
+use super::Units::{seesCell, cellName};
+use super::CandidateSet::candidateSet;
+use super::SudokuIOManager::sudokuIOManager;
+use super::SudokuError::sudokuError;
+
+// Value deleted from a cell by a deduction
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct elimination {
+    pub row: usize,
+    pub column: usize,
+    pub value: i8
+}
+
+// Explanation of the values deleted by a strategy, saved by the sudokuIOManager to show how the sudoku has been solved
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct deduction {
+    // Name of the technique, for example "X-Wing"
+    pub technique: String,
+    // Pattern found by the technique, for example the base and cover sets of a fish
+    pub description: String,
+    pub eliminations: Vec<elimination>
+}
+
+impl deduction {
+    // Deduction constructor, without eliminations
+    pub fn new(technique: &str, description: String) -> deduction {
+        return deduction{technique: technique.to_string(), description, eliminations: Vec::new()};
+    }
+
+    // Add the deletion of the value from the cell in the given position
+    pub fn addElimination(&mut self, row: usize, column: usize, value: i8) {
+        self.eliminations.push(elimination{row, column, value});
+    }
+
+    // Add the deletion of the value from every cell (not excluded and without a definitive value) that sees all the
+    // given cells, if the deduction doesn't already delete it
+    pub fn addCommonPeersEliminations(&mut self, sudokuCopy: &[[candidateSet; 9]; 9], value: i8, cells: &[(usize, usize)], excluded: &[(usize, usize)]) {
+        for i in 0..9 {
+            for j in 0..9 {
+                let deleted = elimination{row: i, column: j, value};
+                if !excluded.contains(&(i, j)) && cells.iter().all(|&cell| seesCell((i, j), cell)) && sudokuCopy[i][j].len() > 1 &&
+                    sudokuCopy[i][j].contains(value) && !self.eliminations.contains(&deleted) {
+                    self.eliminations.push(deleted);
+                }
+            }
+        }
+    }
+}
+
+// Apply the given deductions in their order, keeping only the values that the previous deductions don't delete, so
+// that the same elimination isn't explained by more deductions. Updates signals if there are deleted values
+pub fn applyNewDeductions(ioManager: &sudokuIOManager, deductions: Vec<deduction>) -> Result<bool, sudokuError> {
+    let mut deleted = HashSet::new();
+    let mut updates = false;
+    for mut deductionVar in deductions {
+        deductionVar.eliminations.retain(|eliminationVar| deleted.insert(*eliminationVar));
+        if !deductionVar.eliminations.is_empty() {
+            ioManager.ApplyDeduction(deductionVar)?;
+            updates = true;
+        }
+    }
+    return Ok(updates);
+}
+
+impl fmt::Display for deduction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let eliminations: Vec<String> = self.eliminations.iter()
+            .map(|deleted| format!("{}<>{}", cellName(deleted.row, deleted.column), deleted.value)).collect();
+        write!(f, "{}: {} => {}", self.technique, self.description, eliminations.join(", "))
+    }
+}
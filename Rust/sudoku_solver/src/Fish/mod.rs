@@ -0,0 +1,293 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, sudokuUnit, unitsOfKind, boxIndex};
+use super::Deductions::deduction;
+use super::Subsets::getCombinations;
+use super::StrategyRunners::runThreadsUntilStable;
+
+// Kinds of units used as base sets of the fish, the cover sets are the units of the other kind
+const BASE_KINDS: [unitKind; 2] = [unitKind::Row, unitKind::Column];
+
+// Function that finds the fish of a given size whose base sets are units of a given kind
+type fishFinder = fn(Arc<RwLock<sudokuIOManager>>, unitKind, i8, &[[candidateSet; 9]; 9]) -> Result<bool, sudokuError>;
+
+// Find all the X-Wings, using both rows and columns as base sets
+pub fn findXWings(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findFish(ioManager, 2);
+}
+
+// Find all the Swordfish, using both rows and columns as base sets
+pub fn findSwordfish(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findFish(ioManager, 3);
+}
+
+// Find all the Jellyfish, using both rows and columns as base sets
+pub fn findJellyfish(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findFish(ioManager, 4);
+}
+
+// Find all the finned and sashimi X-Wings, using both rows and columns as base sets
+pub fn findFinnedXWings(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findFinnedFish(ioManager, 2);
+}
+
+// Find all the finned and sashimi Swordfish, using both rows and columns as base sets
+pub fn findFinnedSwordfish(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findFinnedFish(ioManager, 3);
+}
+
+// Find all the finned and sashimi Jellyfish, using both rows and columns as base sets
+pub fn findFinnedJellyfish(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findFinnedFish(ioManager, 4);
+}
+
+// Return the name of the fish of the given size
+pub fn fishName(size: i8) -> &'static str {
+    match size {
+        2 => return "X-Wing",
+        3 => return "Swordfish",
+        _ => return "Jellyfish"
+    }
+}
+
+// Find all the basic fish of the given size (from 2 to 4), until there are no more values to delete
+pub fn findFish(ioManager: Arc<RwLock<sudokuIOManager>>, size: i8) -> Result<(), sudokuError> {
+    return runFish(ioManager, size, findUnitsFish);
+}
+
+// Find all the finned and sashimi fish of the given size (from 2 to 4), until there are no more values to delete
+pub fn findFinnedFish(ioManager: Arc<RwLock<sudokuIOManager>>, size: i8) -> Result<(), sudokuError> {
+    return runFish(ioManager, size, findUnitsFinnedFish);
+}
+
+// Execute the given fish finder for every kind of base sets, until there are no more values to delete
+fn runFish(ioManager: Arc<RwLock<sudokuIOManager>>, size: i8, finder: fishFinder) -> Result<(), sudokuError> {
+    return runThreadsUntilStable(ioManager, &BASE_KINDS, |_sudokuCopy| (), |manager, baseKind, sudokuCopy, _shared| finder(manager, baseKind, size, sudokuCopy));
+}
+
+// Find the fish of the given size whose base sets are units of the given kind. If the possible cells of a value in size
+// base units are all contained in size cover units, the value can be deleted from the other cells of the cover units.
+// Updates signals if there are deleted values
+pub fn findUnitsFish(ioManager: Arc<RwLock<sudokuIOManager>>, baseKind: unitKind, size: i8, sudokuCopy: &[[candidateSet; 9]; 9]) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let coverKind = if baseKind == unitKind::Row { unitKind::Column } else { unitKind::Row };
+    for value in candidateSet::all() {
+        // For every base unit save the mask of the cover units in which the value is possible (the bit n is set if the
+        // value is possible in the cell in position n, which belongs to the cover unit n), and select only the units
+        // that can be part of a fish of this size
+        let mut valuePositions = [0u16; 9];
+        let mut validUnits = Vec::new();
+        for baseUnit in unitsOfKind(baseKind) {
+            for (position, &(i, j)) in baseUnit.cells().iter().enumerate() {
+                if sudokuCopy[i][j].contains(value) {
+                    valuePositions[baseUnit.index] |= 1 << position;
+                }
+            }
+            let positionsNumber = valuePositions[baseUnit.index].count_ones() as i8;
+            if positionsNumber > 1 && positionsNumber <= size {
+                validUnits.push(baseUnit.index as i8);
+            }
+        }
+        // If there are less units than the size of the fish, there can't be fish for this value
+        if (validUnits.len() as i8) < size {
+            continue;
+        }
+        for combination in getCombinations(validUnits, size, 0) {
+            // Get the union of the cover units of the base units of the combination
+            let mut coverPositions = 0u16;
+            for &index in &combination {
+                coverPositions |= valuePositions[index as usize];
+            }
+            if coverPositions.count_ones() as i8 != size {
+                continue;
+            }
+            // The value must be placed in the intersections of base and cover units, so it can be deleted from the other cells of the cover units
+            let baseUnits: Vec<sudokuUnit> = combination.iter().map(|&index| sudokuUnit::new(baseKind, index as usize)).collect();
+            let coverUnits: Vec<sudokuUnit> = (0..9).filter(|index| coverPositions & (1 << index) != 0).map(|index| sudokuUnit::new(coverKind, index)).collect();
+            let mut fish = deduction::new(fishName(size), fishDescription(value, &baseUnits, &coverUnits));
+            for coverUnit in coverUnits.iter() {
+                for &(i, j) in coverUnit.cells().iter() {
+                    if !baseUnits.iter().any(|baseUnit| baseUnit.contains(i, j)) && sudokuCopy[i][j].len() > 1 && sudokuCopy[i][j].contains(value) {
+                        fish.addElimination(i, j, value);
+                    }
+                }
+            }
+            if !fish.eliminations.is_empty() {
+                ioManager.read().unwrap().ApplyDeduction(fish)?;
+                updates = true;
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Find the finned fish of the given size whose base sets are units of the given kind. If the possible cells of a value
+// in size base units are all contained in size cover units except some cells of the same box (the fins), then either
+// the fish is valid or a fin has the value. So the value can be deleted from the cells of the cover units that belong
+// to the box of the fins. If a base unit has only one cell in the cover units the fish is called sashimi.
+// Updates signals if there are deleted values
+pub fn findUnitsFinnedFish(ioManager: Arc<RwLock<sudokuIOManager>>, baseKind: unitKind, size: i8, sudokuCopy: &[[candidateSet; 9]; 9]) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let coverKind = if baseKind == unitKind::Row { unitKind::Column } else { unitKind::Row };
+    for value in candidateSet::all() {
+        // For every base unit save the mask of the cover units in which the value is possible, and select only the
+        // units in which the value doesn't have a definitive position
+        let mut valuePositions = [0u16; 9];
+        let mut validUnits = Vec::new();
+        for baseUnit in unitsOfKind(baseKind) {
+            let mut definitive = false;
+            for (position, &(i, j)) in baseUnit.cells().iter().enumerate() {
+                if sudokuCopy[i][j].contains(value) {
+                    valuePositions[baseUnit.index] |= 1 << position;
+                    definitive = definitive || sudokuCopy[i][j].len() == 1;
+                }
+            }
+            if !definitive && valuePositions[baseUnit.index] != 0 {
+                validUnits.push(baseUnit.index as i8);
+            }
+        }
+        if (validUnits.len() as i8) < size {
+            continue;
+        }
+        for combination in getCombinations(validUnits, size, 0) {
+            // Get the union of the cover units of the base units of the combination, with only size units it's a basic fish
+            let mut allPositions = 0u16;
+            for &index in &combination {
+                allPositions |= valuePositions[index as usize];
+            }
+            if allPositions.count_ones() as i8 <= size {
+                continue;
+            }
+            let baseUnits: Vec<sudokuUnit> = combination.iter().map(|&index| sudokuUnit::new(baseKind, index as usize)).collect();
+            let unionPositions: Vec<i8> = (0..9).filter(|index| allPositions & (1 << index) != 0).collect();
+            // Try every group of size cover units, the cells of the base units outside them are the fins
+            for coverCombination in getCombinations(unionPositions, size, 0) {
+                let coverPositions = coverCombination.iter().fold(0u16, |mask, &index| mask | 1 << index);
+                let mut finsBoxes = Vec::new();
+                let mut sashimi = false;
+                for baseUnit in baseUnits.iter() {
+                    let cells = baseUnit.cells();
+                    for position in 0..9 {
+                        if valuePositions[baseUnit.index] & !coverPositions & (1 << position) != 0 {
+                            let (i, j) = cells[position];
+                            finsBoxes.push(boxIndex(i, j));
+                        }
+                    }
+                    sashimi = sashimi || (valuePositions[baseUnit.index] & coverPositions).count_ones() < 2;
+                }
+                // Every fin must be in the same box
+                if finsBoxes.iter().any(|&finsBox| finsBox != finsBoxes[0]) {
+                    continue;
+                }
+                let finsBox = sudokuUnit::new(unitKind::Box, finsBoxes[0]);
+                let coverUnits: Vec<sudokuUnit> = coverCombination.iter().map(|&index| sudokuUnit::new(coverKind, index as usize)).collect();
+                let technique = format!("{} {}", if sashimi { "Sashimi" } else { "Finned" }, fishName(size));
+                let mut fish = deduction::new(&technique, format!("{}, fins in {}", fishDescription(value, &baseUnits, &coverUnits), finsBox));
+                for coverUnit in coverUnits.iter() {
+                    for &(i, j) in coverUnit.cells().iter() {
+                        if finsBox.contains(i, j) && !baseUnits.iter().any(|baseUnit| baseUnit.contains(i, j)) && sudokuCopy[i][j].len() > 1 && sudokuCopy[i][j].contains(value) {
+                            fish.addElimination(i, j, value);
+                        }
+                    }
+                }
+                if !fish.eliminations.is_empty() {
+                    ioManager.read().unwrap().ApplyDeduction(fish)?;
+                    updates = true;
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Return the description of a fish, with its value and its base and cover sets
+pub fn fishDescription(value: i8, baseUnits: &[sudokuUnit], coverUnits: &[sudokuUnit]) -> String {
+    let baseNames: Vec<String> = baseUnits.iter().map(|unit| unit.to_string()).collect();
+    let coverNames: Vec<String> = coverUnits.iter().map(|unit| unit.to_string()).collect();
+    return format!("value {}, base sets {}, cover sets {}", value, baseNames.join(", "), coverNames.join(", "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, removeValue, managerOf, eliminationsOf, descriptionsOf};
+
+    // Delete the value from every cell of the row except the given columns
+    fn keepInRow(grid: &mut [[candidateSet; 9]; 9], value: i8, row: usize, columns: &[usize]) {
+        removeValue(grid, value, &(0..9).filter(|column| !columns.contains(column)).map(|column| (row, column)).collect::<Vec<_>>());
+    }
+
+    // Return the deductions of the given fish finder, with rows as base sets
+    fn fishOf(grid: [[candidateSet; 9]; 9], finder: fishFinder, size: i8) -> Vec<deduction> {
+        let manager = managerOf(grid);
+        assert!(!finder(manager.clone(), unitKind::Column, size, &grid).unwrap());
+        finder(manager.clone(), unitKind::Row, size, &grid).unwrap();
+        return manager.read().unwrap().GetDeductions();
+    }
+
+    #[test]
+    fn xWing() {
+        // In the rows 1 and 5 the value 3 is possible only in the columns 2 and 7
+        let mut grid = fullGrid();
+        keepInRow(&mut grid, 3, 0, &[1, 6]);
+        keepInRow(&mut grid, 3, 4, &[1, 6]);
+        let deductions = fishOf(grid, findUnitsFish, 2);
+        assert_eq!(descriptionsOf(&deductions), vec!["X-Wing: value 3, base sets row 1, row 5, cover sets column 2, column 7"]);
+        let expected: Vec<(usize, usize, i8)> = [1, 2, 3, 5, 6, 7, 8].iter().flat_map(|&i| vec![(i, 1, 3), (i, 6, 3)]).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn swordfish() {
+        // In the rows 1, 4 and 7 the value 3 is possible only in the columns 2, 5 and 8, two columns for every row
+        let mut grid = fullGrid();
+        keepInRow(&mut grid, 3, 0, &[1, 4]);
+        keepInRow(&mut grid, 3, 3, &[4, 7]);
+        keepInRow(&mut grid, 3, 6, &[1, 7]);
+        let deductions = fishOf(grid, findUnitsFish, 3);
+        assert_eq!(descriptionsOf(&deductions), vec!["Swordfish: value 3, base sets row 1, row 4, row 7, cover sets column 2, column 5, column 8"]);
+        let expected: Vec<(usize, usize, i8)> = [1, 2, 4, 5, 7, 8].iter().flat_map(|&i| vec![(i, 1, 3), (i, 4, 3), (i, 7, 3)]).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn jellyfish() {
+        // In the rows 1, 3, 5 and 7 the value 3 is possible only in the columns 1, 3, 5 and 7, and no two or three rows
+        // share their columns, so there aren't smaller fish
+        let mut grid = fullGrid();
+        keepInRow(&mut grid, 3, 0, &[0, 2]);
+        keepInRow(&mut grid, 3, 2, &[2, 4]);
+        keepInRow(&mut grid, 3, 4, &[4, 6]);
+        keepInRow(&mut grid, 3, 6, &[6, 0]);
+        let deductions = fishOf(grid, findUnitsFish, 4);
+        assert_eq!(descriptionsOf(&deductions), vec!["Jellyfish: value 3, base sets row 1, row 3, row 5, row 7, cover sets column 1, column 3, column 5, column 7"]);
+        let expected: Vec<(usize, usize, i8)> = [1, 3, 5, 7, 8].iter().flat_map(|&i| vec![(i, 0, 3), (i, 2, 3), (i, 4, 3), (i, 6, 3)]).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+        assert!(fishOf(grid, findUnitsFish, 3).is_empty());
+    }
+
+    #[test]
+    fn finnedXWing() {
+        // In the rows 1 and 5 the value 3 is possible only in the columns 2 and 7, except the fin r5c8: either r5c8 is 3 or
+        // the X-Wing is valid, so the cells of the column 7 in the box 6 can't be 3
+        let mut grid = fullGrid();
+        keepInRow(&mut grid, 3, 0, &[1, 6]);
+        keepInRow(&mut grid, 3, 4, &[1, 6, 7]);
+        let deductions = fishOf(grid, findUnitsFinnedFish, 2);
+        assert_eq!(descriptionsOf(&deductions), vec!["Finned X-Wing: value 3, base sets row 1, row 5, cover sets column 2, column 7, fins in box 6"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(3, 6, 3), (5, 6, 3)]);
+    }
+
+    #[test]
+    fn sashimiXWing() {
+        // Without r5c2 the row 5 has only r5c7 in the cover sets, so the fish is sashimi but has the same deletions
+        let mut grid = fullGrid();
+        keepInRow(&mut grid, 3, 0, &[1, 6]);
+        keepInRow(&mut grid, 3, 4, &[6, 7]);
+        let deductions = fishOf(grid, findUnitsFinnedFish, 2);
+        assert_eq!(descriptionsOf(&deductions), vec!["Sashimi X-Wing: value 3, base sets row 1, row 5, cover sets column 2, column 7, fins in box 6"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(3, 6, 3), (5, 6, 3)]);
+    }
+}
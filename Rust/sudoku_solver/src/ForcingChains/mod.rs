@@ -0,0 +1,346 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{allUnits, peers, cellName};
+use super::Deductions::{deduction, applyNewDeductions};
+use super::StrategyRunners::repeatUntilStable;
+
+// Maximum number of propagation steps of the forcing chains used when the solver doesn't specify a different value
+pub const DEFAULT_FORCING_DEPTH: usize = 8;
+
+// Kinds of forcing chains: every value of a cell, every position of a value in a unit, or a single value that leads
+// to a contradiction
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum forcingKind {
+    Cell, Unit, Contradiction
+}
+
+// Consequence of the value assumed by a forcing chain: a value placed in a cell or deleted from it, with the
+// consequences that cause it
+struct forcingFact {
+    placement: bool,
+    cell: (usize, usize),
+    value: i8,
+    causes: Vec<usize>
+}
+
+impl std::fmt::Display for forcingFact {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        return write!(f, "{}{}{}", cellName(self.cell.0, self.cell.1), if self.placement { "=" } else { "<>" }, self.value);
+    }
+}
+
+// Consequences of placing a value in a cell, found by placing the naked and hidden singles for a limited number of
+// steps. If the consequences break the rules the branch contains the contradiction with its causes
+pub struct forcingBranch {
+    facts: Vec<forcingFact>,
+    // Position of the fact that deletes every value from every cell, saved in cell*10+value
+    eliminations: Vec<Option<usize>>,
+    contradiction: Option<(String, Vec<usize>)>
+}
+
+impl forcingBranch {
+    // Place the value in the cell and find its consequences with at most the given number of steps
+    pub fn new(sudokuCopy: &[[candidateSet; 9]; 9], cell: (usize, usize), value: i8, depth: usize) -> forcingBranch {
+        let mut branch = forcingBranch{facts: Vec::new(), eliminations: vec![None; 810], contradiction: None};
+        let mut values = *sudokuCopy;
+        let mut placed = [[false; 9]; 9];
+        for i in 0..9 {
+            for j in 0..9 {
+                placed[i][j] = values[i][j].len() == 1;
+            }
+        }
+        branch.facts.push(forcingFact{placement: true, cell, value, causes: Vec::new()});
+        let mut pending = vec![0];
+        let mut step = 0;
+        while !pending.is_empty() && branch.contradiction.is_none() {
+            // Place the values found in the previous step and delete them from the cells that see them
+            for &placement in pending.iter() {
+                let ((i, j), placedValue) = (branch.facts[placement].cell, branch.facts[placement].value);
+                if !values[i][j].contains(placedValue) {
+                    let mut causes = vec![placement];
+                    causes.extend(branch.eliminationOf((i, j), placedValue));
+                    branch.contradiction = Some((format!("{} can't be {}", cellName(i, j), placedValue), causes));
+                    break;
+                }
+                placed[i][j] = true;
+                for other in values[i][j].difference(candidateSet::single(placedValue)) {
+                    branch.addElimination(&mut values, (i, j), other, placement);
+                }
+                for (pi, pj) in peers(i, j) {
+                    if values[pi][pj].contains(placedValue) {
+                        branch.addElimination(&mut values, (pi, pj), placedValue, placement);
+                    }
+                }
+            }
+            if branch.contradiction.is_none() {
+                branch.contradiction = branch.findContradiction(sudokuCopy, &values);
+            }
+            step += 1;
+            if step > depth || branch.contradiction.is_some() {
+                break;
+            }
+            pending = branch.findSingles(sudokuCopy, &values, &placed);
+        }
+        return branch;
+    }
+
+    // Return the position of the fact that deletes the value from the cell, if it exists
+    pub fn eliminationOf(&self, cell: (usize, usize), value: i8) -> Option<usize> {
+        return self.eliminations[(cell.0*9 + cell.1)*10 + value as usize];
+    }
+
+    // Check if the assumed value leads to a contradiction
+    pub fn isContradiction(&self) -> bool {
+        return self.contradiction.is_some();
+    }
+
+    // Delete the value from the cell, caused by the given fact
+    fn addElimination(&mut self, values: &mut [[candidateSet; 9]; 9], cell: (usize, usize), value: i8, cause: usize) {
+        values[cell.0][cell.1].remove(value);
+        if self.eliminationOf(cell, value).is_none() {
+            self.eliminations[(cell.0*9 + cell.1)*10 + value as usize] = Some(self.facts.len());
+            self.facts.push(forcingFact{placement: false, cell, value, causes: vec![cause]});
+        }
+    }
+
+    // Return the facts that delete the given values from the cell, considering only the values of the initial sudoku
+    fn eliminationCauses(&self, sudokuCopy: &[[candidateSet; 9]; 9], cell: (usize, usize), values: candidateSet) -> Vec<usize> {
+        return sudokuCopy[cell.0][cell.1].intersection(values).iter().filter_map(|value| self.eliminationOf(cell, value)).collect();
+    }
+
+    // Return a contradiction if a cell doesn't have values or a value can't be placed in a unit
+    fn findContradiction(&self, sudokuCopy: &[[candidateSet; 9]; 9], values: &[[candidateSet; 9]; 9]) -> Option<(String, Vec<usize>)> {
+        for i in 0..9 {
+            for j in 0..9 {
+                if values[i][j].is_empty() {
+                    return Some((format!("{} has no values", cellName(i, j)), self.eliminationCauses(sudokuCopy, (i, j), candidateSet::all())));
+                }
+            }
+        }
+        for unit in allUnits() {
+            for value in candidateSet::all() {
+                if unit.cells().iter().all(|&(i, j)| !values[i][j].contains(value)) {
+                    let causes = unit.cells().iter().flat_map(|&cell| self.eliminationCauses(sudokuCopy, cell, candidateSet::single(value))).collect();
+                    return Some((format!("{} can't be placed in {}", value, unit), causes));
+                }
+            }
+        }
+        return None;
+    }
+
+    // Add the naked and hidden singles of the cells without a placed value, and return their positions
+    fn findSingles(&mut self, sudokuCopy: &[[candidateSet; 9]; 9], values: &[[candidateSet; 9]; 9], placed: &[[bool; 9]; 9]) -> Vec<usize> {
+        let mut singles: Vec<((usize, usize), i8)> = Vec::new();
+        let mut pending = Vec::new();
+        for i in 0..9 {
+            for j in 0..9 {
+                if !placed[i][j] && values[i][j].len() == 1 {
+                    let value = values[i][j].first().unwrap();
+                    let causes = self.eliminationCauses(sudokuCopy, (i, j), candidateSet::all().difference(candidateSet::single(value)));
+                    singles.push(((i, j), value));
+                    pending.push(self.facts.len());
+                    self.facts.push(forcingFact{placement: true, cell: (i, j), value, causes});
+                }
+            }
+        }
+        for unit in allUnits() {
+            for value in candidateSet::all() {
+                let cells: Vec<(usize, usize)> = unit.cells().iter().filter(|&&(i, j)| values[i][j].contains(value)).cloned().collect();
+                if cells.len() != 1 || placed[cells[0].0][cells[0].1] || singles.contains(&(cells[0], value)) {
+                    continue;
+                }
+                let causes = unit.cells().iter().filter(|&&cell| cell != cells[0]).flat_map(|&cell| self.eliminationCauses(sudokuCopy, cell, candidateSet::single(value))).collect();
+                singles.push((cells[0], value));
+                pending.push(self.facts.len());
+                self.facts.push(forcingFact{placement: true, cell: cells[0], value, causes});
+            }
+        }
+        return pending;
+    }
+
+    // Return the explanation of the given facts of the branch: the assumed value and every fact that causes them, in
+    // the order in which they have been found
+    fn explain(&self, roots: &[usize]) -> String {
+        let mut used = vec![false; self.facts.len()];
+        let mut toVisit = roots.to_vec();
+        while let Some(factPosition) = toVisit.pop() {
+            if !used[factPosition] {
+                used[factPosition] = true;
+                toVisit.extend(self.facts[factPosition].causes.iter());
+            }
+        }
+        let steps: Vec<String> = (1..self.facts.len()).filter(|&position| used[position]).map(|position| self.facts[position].to_string()).collect();
+        if steps.is_empty() {
+            return self.facts[0].to_string();
+        }
+        return format!("{} => {}", self.facts[0], steps.join(", "));
+    }
+
+    // Return the explanation of the contradiction of the branch, or of the deletion of the given values
+    fn explainEliminations(&self, eliminations: &[((usize, usize), i8)]) -> String {
+        if let Some((description, causes)) = &self.contradiction {
+            return format!("{} => contradiction, {}", self.explain(causes), description);
+        }
+        let roots: Vec<usize> = eliminations.iter().filter_map(|&(cell, value)| self.eliminationOf(cell, value)).collect();
+        return self.explain(&roots);
+    }
+}
+
+// Find all the given kinds of forcing chains with at most the given number of steps, until there are no more values to delete
+pub fn findForcingChains(ioManager: Arc<RwLock<sudokuIOManager>>, kinds: &[forcingKind], depth: usize) -> Result<(), sudokuError> {
+    if kinds.is_empty() {
+        return Ok(());
+    }
+    return repeatUntilStable(ioManager, |manager| findGridForcingChains(manager, kinds, depth));
+}
+
+// Find the consequences of every value of the cells without a definitive value, and the deductions of the given kinds
+// of forcing chains. A value that leads to a contradiction can be deleted, while the values deleted by every value of
+// a cell, or by every position of a value in a unit, can be deleted. Updates signals if there are deleted values
+pub fn findGridForcingChains(ioManager: Arc<RwLock<sudokuIOManager>>, kinds: &[forcingKind], depth: usize) -> Result<bool, sudokuError> {
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    // The branches of every value, saved in cell*10+value
+    let mut branches: Vec<Option<forcingBranch>> = (0..810).map(|_position| None).collect();
+    for i in 0..9 {
+        for j in 0..9 {
+            if sudokuCopy[i][j].len() > 1 {
+                for value in sudokuCopy[i][j] {
+                    branches[(i*9 + j)*10 + value as usize] = Some(forcingBranch::new(&sudokuCopy, (i, j), value, depth));
+                }
+            }
+        }
+    }
+    let branchOf = |cell: (usize, usize), value: i8| branches[(cell.0*9 + cell.1)*10 + value as usize].as_ref().unwrap();
+
+    let mut deductions = Vec::new();
+    for &kind in kinds.iter() {
+        match kind {
+            forcingKind::Contradiction => {
+                for i in 0..9 {
+                    for j in 0..9 {
+                        for value in sudokuCopy[i][j].iter().filter(|&value| sudokuCopy[i][j].len() > 1 && branchOf((i, j), value).isContradiction()) {
+                            let mut contradiction = deduction::new("Contradiction Chain", branchOf((i, j), value).explainEliminations(&[]));
+                            contradiction.addElimination(i, j, value);
+                            deductions.push(contradiction);
+                        }
+                    }
+                }
+            }
+            forcingKind::Cell => {
+                for i in 0..9 {
+                    for j in 0..9 {
+                        if sudokuCopy[i][j].len() > 1 {
+                            let cellBranches: Vec<&forcingBranch> = sudokuCopy[i][j].iter().map(|value| branchOf((i, j), value)).collect();
+                            deductions.extend(forcingDeduction(&sudokuCopy, "Cell Forcing Chain", &format!("every value of {}", cellName(i, j)), &cellBranches));
+                        }
+                    }
+                }
+            }
+            forcingKind::Unit => {
+                for unit in allUnits() {
+                    for value in candidateSet::all() {
+                        let cells: Vec<(usize, usize)> = unit.cells().iter().filter(|&&(i, j)| sudokuCopy[i][j].contains(value)).cloned().collect();
+                        if cells.len() < 2 || cells.iter().any(|&(i, j)| sudokuCopy[i][j].len() == 1) {
+                            continue;
+                        }
+                        let unitBranches: Vec<&forcingBranch> = cells.iter().map(|&cell| branchOf(cell, value)).collect();
+                        deductions.extend(forcingDeduction(&sudokuCopy, "Unit Forcing Chain", &format!("every position of {} in {}", value, unit), &unitBranches));
+                    }
+                }
+            }
+        }
+    }
+
+    return applyNewDeductions(&ioManager.read().unwrap(), deductions);
+}
+
+// Return the deduction of the values deleted by every given branch, one of which must be true. A branch that leads
+// to a contradiction can't be true, so it doesn't limit the deleted values
+fn forcingDeduction(sudokuCopy: &[[candidateSet; 9]; 9], technique: &str, source: &str, branches: &[&forcingBranch]) -> Option<deduction> {
+    if branches.iter().all(|branch| branch.isContradiction()) {
+        return None;
+    }
+    let mut eliminations = Vec::new();
+    for i in 0..9 {
+        for j in 0..9 {
+            if sudokuCopy[i][j].len() < 2 {
+                continue;
+            }
+            for value in sudokuCopy[i][j] {
+                if branches.iter().all(|branch| branch.isContradiction() || branch.eliminationOf((i, j), value).is_some()) {
+                    eliminations.push(((i, j), value));
+                }
+            }
+        }
+    }
+    if eliminations.is_empty() {
+        return None;
+    }
+    let explanations: Vec<String> = branches.iter().map(|branch| format!("[{}]", branch.explainEliminations(&eliminations))).collect();
+    let mut forcing = deduction::new(technique, format!("{}: {}", source, explanations.join(" ")));
+    for &((i, j), value) in eliminations.iter() {
+        forcing.addElimination(i, j, value);
+    }
+    return Some(forcing);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, removeValue, managerOf, eliminationsOf, descriptionsOf};
+
+    // Return the deductions of the given kind of forcing chains
+    fn forcingOf(grid: [[candidateSet; 9]; 9], kind: forcingKind) -> Vec<deduction> {
+        let manager = managerOf(grid);
+        findGridForcingChains(manager.clone(), &[kind], DEFAULT_FORCING_DEPTH).unwrap();
+        return manager.read().unwrap().GetDeductions();
+    }
+
+    #[test]
+    fn contradictionChain() {
+        // r1c1 has only 1 and 2, r1c2 and r1c3 only 1 and 3: r1c1 can't be 1, because it would leave 3 for both r1c2 and
+        // r1c3. In the same way 1 and 3 can't be in the cells that see r1c2 and r1c3, and 2 in the cells that see r1c1
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(0, 1), (0, 2)]);
+        let deductions = forcingOf(grid, forcingKind::Contradiction);
+        assert_eq!(descriptionsOf(&deductions[..1]), vec!["Contradiction Chain: r1c1=1 => r1c2<>1, r1c3<>1, r1c2=3, r1c3=3, r1c3<>3 => contradiction, r1c3 can't be 3"]);
+        let mut expected = vec![(0, 0, 1)];
+        let bothCells: Vec<(usize, usize)> = (3..9).map(|j| (0, j)).chain((1..3).flat_map(|i| (0..3).map(move |j| (i, j)))).collect();
+        expected.extend(bothCells.iter().flat_map(|&(i, j)| (1..4).map(move |value| (i, j, value))));
+        expected.extend((3..9).map(|i| (i, 0, 2)));
+        expected.sort_unstable();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn cellForcingChain() {
+        // r1c1 has only 1 and 2, r1c5 only 1 and 5, and r5c1 only 2 and 5: with both the values of r1c1 one of the other
+        // cells is 5, so r5c5 can't be 5
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 5], &[(0, 4)]);
+        setValues(&mut grid, &[2, 5], &[(4, 0)]);
+        let deductions = forcingOf(grid, forcingKind::Cell);
+        assert_eq!(descriptionsOf(&deductions), vec!["Cell Forcing Chain: every value of r1c1: [r1c1=1 => r1c5<>1, r1c5=5, r5c5<>5] [r1c1=2 => r5c1<>2, r5c1=5, r5c5<>5]"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(4, 4, 5)]);
+    }
+
+    #[test]
+    fn unitForcingChain() {
+        // In the row 1 the value 5 is possible only in r1c1 and r1c5, while r2c2 and r3c5 have only 5 and 8: with both the
+        // positions of 5 one of r2c2 and r3c5 is 8, so the cells that see both can't be 8
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 5, &[(0, 1), (0, 2), (0, 3), (0, 5), (0, 6), (0, 7), (0, 8)]);
+        setValues(&mut grid, &[5, 8], &[(1, 1), (2, 4)]);
+        let deductions = forcingOf(grid, forcingKind::Unit);
+        assert_eq!(descriptionsOf(&deductions), vec!["Unit Forcing Chain: every position of 5 in row 1: \
+                                                      [r1c1=5 => r2c2<>5, r2c2=8, r2c4<>8, r2c5<>8, r2c6<>8, r3c1<>8, r3c2<>8, r3c3<>8] \
+                                                      [r1c5=5 => r3c5<>5, r3c5=8, r2c4<>8, r2c5<>8, r2c6<>8, r3c1<>8, r3c2<>8, r3c3<>8]"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(1, 3, 8), (1, 4, 8), (1, 5, 8), (2, 0, 8), (2, 1, 8), (2, 2, 8)]);
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, sudokuUnit, cellName};
+use super::Deductions::deduction;
+use super::StrategyRunners::repeatUntilStable;
+
+// Function that finds the hidden singles of every unit of a kind
+type hiddenSinglesFinder = fn(Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError>;
+
+// Finders of the hidden singles of rows, columns and boxes, in the order in which they are executed
+const FINDERS: [hiddenSinglesFinder; 3] = [findHorizontalHiddenSingles, findVerticalHiddenSingles, findBoxesHiddenSingles];
+
+// Find all the horizontal, vertical and boxes hidden singles
+pub fn findHiddenSingles(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    // The kinds of units are analyzed in sequence, so a cell placed by a kind is already definitive for the following
+    // ones and a hidden single of more units is recorded only once
+    return repeatUntilStable(ioManager, |manager| {
+        let mut updates = false;
+        for finder in FINDERS.iter() {
+            if finder(manager.clone())? {
+                updates = true;
+            }
+        }
+        return Ok(updates);
+    });
+}
+
+// Find the values that can be placed in only one cell of a row, and make them definitive
+pub fn findHorizontalHiddenSingles(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for i in 0..9 {
+        for value in candidateSet::all() {
+            // Save the index of every cell of the row in which the value is possible
+            let mut validCellsPosition = Vec::new();
+            for j in 0..9 {
+                if sudokuCopy[i][j].contains(value) {
+                    validCellsPosition.push(j);
+                }
+            }
+            // If there is only one cell without a definitive value, the value must be placed there
+            if validCellsPosition.len() == 1 && sudokuCopy[i][validCellsPosition[0]].len() > 1 {
+                let j = validCellsPosition[0];
+                ioManager.read().unwrap().ApplyDeduction(hiddenSingleDeduction(&sudokuCopy, sudokuUnit::new(unitKind::Row, i), (i, j), value))?;
+                updates = true;
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Find the values that can be placed in only one cell of a column, and make them definitive
+pub fn findVerticalHiddenSingles(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for j in 0..9 {
+        for value in candidateSet::all() {
+            // Save the index of every cell of the column in which the value is possible
+            let mut validCellsPosition = Vec::new();
+            for i in 0..9 {
+                if sudokuCopy[i][j].contains(value) {
+                    validCellsPosition.push(i);
+                }
+            }
+            // If there is only one cell without a definitive value, the value must be placed there
+            if validCellsPosition.len() == 1 && sudokuCopy[validCellsPosition[0]][j].len() > 1 {
+                let i = validCellsPosition[0];
+                ioManager.read().unwrap().ApplyDeduction(hiddenSingleDeduction(&sudokuCopy, sudokuUnit::new(unitKind::Column, j), (i, j), value))?;
+                updates = true;
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Find the values that can be placed in only one cell of a box, and make them definitive
+pub fn findBoxesHiddenSingles(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for i in 0..3 {
+        for j in 0..3 {
+            for value in candidateSet::all() {
+                // Save the position of every cell of the box in which the value is possible
+                let mut validCellsPosition = Vec::new();
+                for ib in 0..3 {
+                    for jb in 0..3 {
+                        if sudokuCopy[i*3+ib][j*3+jb].contains(value) {
+                            validCellsPosition.push((i*3+ib, j*3+jb));
+                        }
+                    }
+                }
+                // If there is only one cell without a definitive value, the value must be placed there
+                if validCellsPosition.len() == 1 {
+                    let (row, column) = validCellsPosition[0];
+                    if sudokuCopy[row][column].len() > 1 {
+                        let boxUnit = sudokuUnit::new(unitKind::Box, i*3 + j);
+                        ioManager.read().unwrap().ApplyDeduction(hiddenSingleDeduction(&sudokuCopy, boxUnit, (row, column), value))?;
+                        updates = true;
+                    }
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Return the deduction of the hidden single of the value in the unit: the other values of the cell can be deleted
+fn hiddenSingleDeduction(sudokuCopy: &[[candidateSet; 9]; 9], unit: sudokuUnit, cell: (usize, usize), value: i8) -> deduction {
+    let mut single = deduction::new("Hidden Single", format!("value {} of {} only in {}", value, unit, cellName(cell.0, cell.1)));
+    for otherValue in sudokuCopy[cell.0][cell.1].difference(candidateSet::single(value)) {
+        single.addElimination(cell.0, cell.1, otherValue);
+    }
+    return single;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, removeValue, managerOf, eliminationsOf, techniquesOf};
+
+    #[test]
+    fn hiddenSingleOfRow() {
+        // In the row 2 the value 5 is possible only in r2c7
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = (0..9).filter(|&j| j != 6).map(|j| (1, j)).collect();
+        removeValue(&mut grid, 5, &otherCells);
+        let manager = managerOf(grid);
+        assert!(!findVerticalHiddenSingles(manager.clone()).unwrap());
+        assert!(!findBoxesHiddenSingles(manager.clone()).unwrap());
+        assert!(findHorizontalHiddenSingles(manager.clone()).unwrap());
+        let readManager = manager.read().unwrap();
+        let deductions = readManager.GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Hidden Single"]);
+        assert_eq!(eliminationsOf(&deductions), [1, 2, 3, 4, 6, 7, 8, 9].iter().map(|&value| (1, 6, value)).collect::<Vec<_>>());
+        assert_eq!(readManager.GetSudoku().unwrap()[1][6], candidateSet::single(5));
+    }
+
+    #[test]
+    fn hiddenSingleOfBox() {
+        // In the box 5 the value 2 is possible only in r5c6
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = sudokuUnit::new(unitKind::Box, 4).cells().iter().filter(|&&cell| cell != (4, 5)).cloned().collect();
+        removeValue(&mut grid, 2, &otherCells);
+        let manager = managerOf(grid);
+        assert!(!findHorizontalHiddenSingles(manager.clone()).unwrap());
+        assert!(findBoxesHiddenSingles(manager.clone()).unwrap());
+        assert_eq!(manager.read().unwrap().GetSudoku().unwrap()[4][5], candidateSet::single(2));
+    }
+    #[test]
+    fn hiddenSingleOfRowAndBox() {
+        // In the row 2 and in the box 3 the value 5 is possible only in r2c7, which is placed only once
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = sudokuUnit::new(unitKind::Box, 2).cells().iter().cloned()
+            .chain((0..6).map(|j| (1, j))).filter(|&cell| cell != (1, 6)).collect();
+        removeValue(&mut grid, 5, &otherCells);
+        let manager = managerOf(grid);
+        findHiddenSingles(manager.clone()).unwrap();
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Hidden Single"]);
+        assert_eq!(deductions[0].description, "value 5 of row 2 only in r2c7");
+    }
+}
@@ -0,0 +1,247 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, sudokuUnit, unitsOfKind, seesCell, cellName};
+use super::StrongLinks::{strongLink, strongLinkIndex};
+use super::Deductions::deduction;
+use super::StrategyRunners::runThreadsUntilStable;
+
+// Patterns based on the strong links of the sudoku
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum linkPattern {
+    Skyscraper, TwoStringKite, EmptyRectangle, WWing
+}
+
+// Find all the given patterns, until there are no more values to delete. The strong links are found once for every
+// pass and shared by the threads of the patterns
+pub fn findLinkPatterns(ioManager: Arc<RwLock<sudokuIOManager>>, patterns: &[linkPattern]) -> Result<(), sudokuError> {
+    return runThreadsUntilStable(ioManager, patterns, strongLinkIndex::new, findPattern);
+}
+
+// Find every occurrence of the given pattern and delete the corresponding values, updates signals if there are deleted values
+pub fn findPattern(ioManager: Arc<RwLock<sudokuIOManager>>, pattern: linkPattern, sudokuCopy: &[[candidateSet; 9]; 9], links: &strongLinkIndex) -> Result<bool, sudokuError> {
+    let deductions = match pattern {
+        linkPattern::Skyscraper => findSkyscrapers(sudokuCopy, links),
+        linkPattern::TwoStringKite => findTwoStringKites(sudokuCopy, links),
+        linkPattern::EmptyRectangle => findEmptyRectangles(sudokuCopy, links),
+        linkPattern::WWing => findWWings(sudokuCopy, links)
+    };
+    let updates = !deductions.is_empty();
+    let readManager = ioManager.read().unwrap();
+    for deductionVar in deductions {
+        readManager.ApplyDeduction(deductionVar)?;
+    }
+    return Ok(updates);
+}
+
+// Find the Skyscrapers: two strong links of a value in parallel lines, with one end of each link in the same
+// perpendicular line. One of the other two ends must have the value, so it can be deleted from the cells that see both
+pub fn findSkyscrapers(sudokuCopy: &[[candidateSet; 9]; 9], links: &strongLinkIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    for value in candidateSet::all() {
+        let valueLinks = links.linksOfValue(value);
+        for (position, first) in valueLinks.iter().enumerate() {
+            for second in valueLinks[position+1..].iter() {
+                if first.unit.kind == unitKind::Box || first.unit.kind != second.unit.kind || first.unit == second.unit {
+                    continue;
+                }
+                for &(firstBase, firstEnd) in [(first.first, first.second), (first.second, first.first)].iter() {
+                    for &(secondBase, secondEnd) in [(second.first, second.second), (second.second, second.first)].iter() {
+                        // The bases must be in the same perpendicular line, the other ends must not
+                        if sameCrossLine(first.unit.kind, firstBase, secondBase) && !sameCrossLine(first.unit.kind, firstEnd, secondEnd) {
+                            let mut skyscraper = deduction::new("Skyscraper", format!("value {}, strong links {} and {}", value, linkName(first), linkName(second)));
+                            skyscraper.addCommonPeersEliminations(sudokuCopy, value, &[firstEnd, secondEnd], &[firstBase, secondBase]);
+                            if !skyscraper.eliminations.is_empty() {
+                                deductions.push(skyscraper);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return deductions;
+}
+
+// Find the Two-String Kites: a strong link of a value in a row and one in a column, with one end of each link in the
+// same box. One of the other two ends must have the value, so it can be deleted from the cells that see both
+pub fn findTwoStringKites(sudokuCopy: &[[candidateSet; 9]; 9], links: &strongLinkIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    for value in candidateSet::all() {
+        let valueLinks = links.linksOfValue(value);
+        for rowLink in valueLinks.iter().filter(|link| link.unit.kind == unitKind::Row) {
+            for columnLink in valueLinks.iter().filter(|link| link.unit.kind == unitKind::Column) {
+                for &(rowBase, rowEnd) in [(rowLink.first, rowLink.second), (rowLink.second, rowLink.first)].iter() {
+                    for &(columnBase, columnEnd) in [(columnLink.first, columnLink.second), (columnLink.second, columnLink.first)].iter() {
+                        // The four cells must be different and the bases must be in the same box (but not in the same line)
+                        let cells = [rowBase, rowEnd, columnBase, columnEnd];
+                        if (0..4).any(|x| (x+1..4).any(|y| cells[x] == cells[y])) || !sudokuUnit::ofCell(unitKind::Box, rowBase.0, rowBase.1).contains(columnBase.0, columnBase.1) {
+                            continue;
+                        }
+                        let mut kite = deduction::new("Two-String Kite", format!("value {}, strong links {} and {}", value, linkName(rowLink), linkName(columnLink)));
+                        kite.addCommonPeersEliminations(sudokuCopy, value, &[rowEnd, columnEnd], &[rowBase, columnBase]);
+                        if !kite.eliminations.is_empty() {
+                            deductions.push(kite);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return deductions;
+}
+
+// Find the Empty Rectangles: the possible cells of a value in a box are all in one row and one column of the box, and a
+// strong link outside the box has an end in that row (or column). Then the cell in the column (or row) of the box and in
+// the line of the other end of the link can't have the value, otherwise the box couldn't contain it
+pub fn findEmptyRectangles(sudokuCopy: &[[candidateSet; 9]; 9], links: &strongLinkIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    for value in candidateSet::all() {
+        for boxUnit in unitsOfKind(unitKind::Box) {
+            let boxCells: Vec<(usize, usize)> = boxUnit.cells().iter().filter(|&&(i, j)| sudokuCopy[i][j].contains(value)).cloned().collect();
+            if boxCells.len() < 2 || boxCells.iter().any(|&(i, j)| sudokuCopy[i][j].len() == 1) {
+                continue;
+            }
+            let (firstRow, firstColumn) = boxUnit.cells()[0];
+            for row in firstRow..firstRow+3 {
+                for column in firstColumn..firstColumn+3 {
+                    // Every cell must be in the row or in the column, with at least a cell outside each of them
+                    if !boxCells.iter().all(|&(i, j)| i == row || j == column) || boxCells.iter().all(|&(i, _j)| i == row) || boxCells.iter().all(|&(_i, j)| j == column) {
+                        continue;
+                    }
+                    for link in links.linksOfValue(value).iter() {
+                        for &(linkBase, linkEnd) in [(link.first, link.second), (link.second, link.first)].iter() {
+                            // The link must be in a column with an end in the row of the box, or in a row with an end in the column of the box
+                            let target = match link.unit.kind {
+                                unitKind::Column if linkBase.0 == row && linkEnd.0 / 3 != firstRow / 3 && linkBase.1 / 3 != firstColumn / 3 => (linkEnd.0, column),
+                                unitKind::Row if linkBase.1 == column && linkEnd.1 / 3 != firstColumn / 3 && linkBase.0 / 3 != firstRow / 3 => (row, linkEnd.1),
+                                _ => continue
+                            };
+                            if sudokuCopy[target.0][target.1].len() > 1 && sudokuCopy[target.0][target.1].contains(value) {
+                                let description = format!("value {}, {} with {} and {}, strong link {}", value, boxUnit,
+                                                          sudokuUnit::new(unitKind::Row, row), sudokuUnit::new(unitKind::Column, column), linkName(link));
+                                let mut emptyRectangle = deduction::new("Empty Rectangle", description);
+                                emptyRectangle.addElimination(target.0, target.1, value);
+                                deductions.push(emptyRectangle);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return deductions;
+}
+
+// Find the W-Wings: two cells that don't see each other have the same two values xy, and a strong link of x has an end
+// that sees the first cell and the other end that sees the second one. So one of the two cells must be y, and y can be
+// deleted from the cells that see both
+pub fn findWWings(sudokuCopy: &[[candidateSet; 9]; 9], links: &strongLinkIndex) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    let mut bivalueCells = Vec::new();
+    for i in 0..9 {
+        for j in 0..9 {
+            if sudokuCopy[i][j].len() == 2 {
+                bivalueCells.push((i, j));
+            }
+        }
+    }
+    for (position, &first) in bivalueCells.iter().enumerate() {
+        for &second in bivalueCells[position+1..].iter() {
+            let values = sudokuCopy[first.0][first.1];
+            if values != sudokuCopy[second.0][second.1] || seesCell(first, second) {
+                continue;
+            }
+            for x in values {
+                let y = values.difference(candidateSet::single(x)).first().unwrap();
+                for link in links.linksOfValue(x).iter() {
+                    if link.contains(first) || link.contains(second) {
+                        continue;
+                    }
+                    if (seesCell(link.first, first) && seesCell(link.second, second)) || (seesCell(link.second, first) && seesCell(link.first, second)) {
+                        let description = format!("cells {} and {} with values {}, strong link {}", cellName(first.0, first.1), cellName(second.0, second.1), values, linkName(link));
+                        let mut wing = deduction::new("W-Wing", description);
+                        wing.addCommonPeersEliminations(sudokuCopy, y, &[first, second], &[]);
+                        if !wing.eliminations.is_empty() {
+                            deductions.push(wing);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return deductions;
+}
+
+// Check if the two cells are in the same line perpendicular to the lines of the given kind
+fn sameCrossLine(kind: unitKind, first: (usize, usize), second: (usize, usize)) -> bool {
+    if kind == unitKind::Row {
+        return first.1 == second.1;
+    }
+    return first.0 == second.0;
+}
+
+// Return the description of a strong link, with its cells and its unit
+fn linkName(link: &strongLink) -> String {
+    return format!("{}-{} in {}", cellName(link.first.0, link.first.1), cellName(link.second.0, link.second.1), link.unit);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, removeValue, eliminationsOf, descriptionsOf};
+
+    // Delete the value from every cell of the column except the given rows
+    fn keepInColumn(grid: &mut [[candidateSet; 9]; 9], value: i8, column: usize, rows: &[usize]) {
+        removeValue(grid, value, &(0..9).filter(|row| !rows.contains(row)).map(|row| (row, column)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn skyscraper() {
+        // The strong links r3c1=r9c1 and r1c5=r9c5 of the value 1 have the bases in the row 9, so r3c1 or r1c5 is 1
+        let mut grid = fullGrid();
+        keepInColumn(&mut grid, 1, 0, &[2, 8]);
+        keepInColumn(&mut grid, 1, 4, &[0, 8]);
+        let deductions = findSkyscrapers(&grid, &strongLinkIndex::new(&grid));
+        assert_eq!(descriptionsOf(&deductions), vec!["Skyscraper: value 1, strong links r3c1-r9c1 in column 1 and r1c5-r9c5 in column 5"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 1, 1), (0, 2, 1), (2, 3, 1), (2, 5, 1)]);
+    }
+
+    #[test]
+    fn twoStringKite() {
+        // The strong links r1c2=r1c7 and r3c1=r8c1 of the value 1 have r1c2 and r3c1 in the box 1, so r1c7 or r8c1 is 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 0), (0, 2), (0, 3), (0, 4), (0, 5), (0, 7), (0, 8)]);
+        keepInColumn(&mut grid, 1, 0, &[2, 7]);
+        let links = strongLinkIndex::new(&grid);
+        assert!(findSkyscrapers(&grid, &links).is_empty());
+        let deductions = findTwoStringKites(&grid, &links);
+        assert_eq!(descriptionsOf(&deductions), vec!["Two-String Kite: value 1, strong links r1c2-r1c7 in row 1 and r3c1-r8c1 in column 1"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(7, 6, 1)]);
+    }
+
+    #[test]
+    fn emptyRectangle() {
+        // In the box 1 the value 1 is possible only in the row 2 and in the column 2, and the strong link r2c6=r7c6 has
+        // an end in the row 2: if r7c6 isn't 1 then r2c6 is, so the box has 1 in the column 2 and r7c2 can't be 1
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 1, &[(0, 0), (0, 2), (2, 0), (2, 2)]);
+        keepInColumn(&mut grid, 1, 5, &[1, 6]);
+        let deductions = findEmptyRectangles(&grid, &strongLinkIndex::new(&grid));
+        assert_eq!(descriptionsOf(&deductions), vec!["Empty Rectangle: value 1, box 1 with row 2 and column 2, strong link r2c6-r7c6 in column 6"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(6, 1, 1)]);
+    }
+
+    #[test]
+    fn wWing() {
+        // r1c1 and r5c5 have only 1 and 2, and the strong link r3c3=r5c3 of 1 has an end that sees each of them: one of
+        // the two cells is 1, so the other one is 2 and the cells that see both can't be 2
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0), (4, 4)]);
+        keepInColumn(&mut grid, 1, 2, &[2, 4]);
+        let deductions = findWWings(&grid, &strongLinkIndex::new(&grid));
+        assert_eq!(descriptionsOf(&deductions), vec!["W-Wing: cells r1c1 and r5c5 with values {1,2}, strong link r3c3-r5c3 in column 3"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 4, 2), (4, 0, 2)]);
+    }
+}
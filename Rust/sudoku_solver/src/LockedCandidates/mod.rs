@@ -0,0 +1,96 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, sudokuUnit, unitsOfKind};
+use super::Deductions::deduction;
+use super::StrategyRunners::runThreadsUntilStable;
+
+// Couples of unit kinds analyzed by the pointing: if the possible cells of a value in a box are all in the same row
+// (or column), the value can be deleted from the other cells of that row (or column)
+const POINTING_UNITS: [(unitKind, unitKind); 2] = [(unitKind::Box, unitKind::Row), (unitKind::Box, unitKind::Column)];
+// Couples of unit kinds analyzed by the claiming: if the possible cells of a value in a row (or column) are all in the
+// same box, the value can be deleted from the other cells of that box
+const CLAIMING_UNITS: [(unitKind, unitKind); 2] = [(unitKind::Row, unitKind::Box), (unitKind::Column, unitKind::Box)];
+
+// Find all the pointing pairs and triples of the boxes
+pub fn findPointingCandidates(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findLockedCandidates(ioManager, &POINTING_UNITS);
+}
+
+// Find all the box/line reductions of the rows and columns
+pub fn findClaimingCandidates(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findLockedCandidates(ioManager, &CLAIMING_UNITS);
+}
+
+// Find the locked candidates for every couple of unit kinds given in input, until there are no more values to delete
+fn findLockedCandidates(ioManager: Arc<RwLock<sudokuIOManager>>, unitKinds: &[(unitKind, unitKind)]) -> Result<(), sudokuError> {
+    return runThreadsUntilStable(ioManager, unitKinds, |_sudokuCopy| (), |manager, (baseKind, coverKind), sudokuCopy, _shared| findUnitsLockedCandidates(manager, baseKind, coverKind, sudokuCopy));
+}
+
+// Find the values whose possible cells in a unit of the base kind are all in the same unit of the cover kind, and delete
+// them from the other cells of the cover unit. Updates signals if there are deleted values
+pub fn findUnitsLockedCandidates(ioManager: Arc<RwLock<sudokuIOManager>>, baseKind: unitKind, coverKind: unitKind, sudokuCopy: &[[candidateSet; 9]; 9]) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    for baseUnit in unitsOfKind(baseKind) {
+        for value in candidateSet::all() {
+            // Save the position of every cell of the base unit in which the value is possible
+            let validCellsPosition: Vec<(usize, usize)> = baseUnit.cells().iter().filter(|&&(i, j)| sudokuCopy[i][j].contains(value)).cloned().collect();
+            // With only one cell the value is a hidden single, which is found by another strategy
+            if validCellsPosition.len() < 2 {
+                continue;
+            }
+            // Check if every cell belongs to the same cover unit
+            let (firstRow, firstColumn) = validCellsPosition[0];
+            let coverUnit = sudokuUnit::ofCell(coverKind, firstRow, firstColumn);
+            if validCellsPosition.iter().all(|&(i, j)| coverUnit.contains(i, j)) {
+                // The value must be in the intersection of the two units, so delete it from the other cells of the cover unit
+                let technique = if baseKind == unitKind::Box { "Pointing" } else { "Claiming" };
+                let mut locked = deduction::new(technique, format!("value {} of {} only in {}", value, baseUnit, coverUnit));
+                for &(i, j) in coverUnit.cells().iter() {
+                    if !baseUnit.contains(i, j) && sudokuCopy[i][j].len() > 1 && sudokuCopy[i][j].contains(value) {
+                        locked.addElimination(i, j, value);
+                    }
+                }
+                if !locked.eliminations.is_empty() {
+                    ioManager.read().unwrap().ApplyDeduction(locked)?;
+                    updates = true;
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, removeValue, managerOf, eliminationsOf, techniquesOf};
+
+    #[test]
+    fn pointingPair() {
+        // In the box 1 the value 7 is possible only in r2c1 and r2c3, so it can be deleted from the rest of the row 2
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = sudokuUnit::new(unitKind::Box, 0).cells().iter().filter(|&&cell| cell != (1, 0) && cell != (1, 2)).cloned().collect();
+        removeValue(&mut grid, 7, &otherCells);
+        let manager = managerOf(grid);
+        assert!(findUnitsLockedCandidates(manager.clone(), unitKind::Box, unitKind::Row, &grid).unwrap());
+        assert!(!findUnitsLockedCandidates(manager.clone(), unitKind::Box, unitKind::Column, &grid).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Pointing"]);
+        assert_eq!(eliminationsOf(&deductions), (3..9).map(|j| (1, j, 7)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn claimingTriple() {
+        // In the column 9 the value 4 is possible only in the box 9, so it can be deleted from the rest of the box
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 4, &(0..6).map(|i| (i, 8)).collect::<Vec<_>>());
+        let manager = managerOf(grid);
+        assert!(findUnitsLockedCandidates(manager.clone(), unitKind::Column, unitKind::Box, &grid).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Claiming"]);
+        let expected: Vec<(usize, usize, i8)> = (6..9).flat_map(|i| (6..8).map(move |j| (i, j, 4))).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+}
@@ -0,0 +1,57 @@
+extern crate crossbeam;
+use std::sync::{Arc, RwLock};
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::SudokuIOManager::sudokuIOManager;
+use super::ConstraintsElimination::constraintsElimination;
+
+// Execute the finder in a thread for every item, until none of them deletes a value. In every pass the threads analyze
+// the same copy of the sudoku and share the data prepared from it, and if there are deleted values the constraints
+// are updated before the next pass
+pub fn runThreadsUntilStable<T, S, P, F>(ioManager: Arc<RwLock<sudokuIOManager>>, items: &[T], prepare: P, finder: F) -> Result<(), sudokuError>
+    where T: Copy + Send, S: Sync, P: Fn(&[[candidateSet; 9]; 9]) -> S,
+          F: Fn(Arc<RwLock<sudokuIOManager>>, T, &[[candidateSet; 9]; 9], &S) -> Result<bool, sudokuError> + Sync {
+    // Boolean to check if there are deleted values in the following threads
+    let mut updates = !items.is_empty();
+
+    // While there is at least one thread that updates one or more values, the threads must be executed again
+    while updates {
+        let readManager = ioManager.read().unwrap();
+        let sudokuCopy = readManager.GetSudoku()?;
+        drop(readManager);
+        let shared = prepare(&sudokuCopy);
+        let responses: Vec<Result<bool, sudokuError>> = crossbeam::scope(|scope| {
+            // Start a thread for every item, and wait all of them
+            let threads: Vec<_> = items.iter().map(|&item| {
+                let manager = ioManager.clone();
+                let (sudokuCopy, shared, finder) = (&sudokuCopy, &shared, &finder);
+                return scope.spawn(move |_var| finder(manager, item, sudokuCopy, shared));
+            }).collect();
+            return threads.into_iter().map(|thread| thread.join().unwrap()).collect();
+        }).unwrap();
+
+        // Check the response of every thread
+        updates = false;
+        for response in responses {
+            if response? {
+                updates = true;
+            }
+        }
+
+        if updates {
+            // If there are deleted values then the constraints must be updated
+            constraintsElimination(ioManager.clone())?;
+        }
+    }
+    return Ok(());
+}
+
+// Execute the finder until it doesn't delete values, updating the constraints after every execution that deletes them
+pub fn repeatUntilStable<F>(ioManager: Arc<RwLock<sudokuIOManager>>, mut finder: F) -> Result<(), sudokuError>
+    where F: FnMut(Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    while finder(ioManager.clone())? {
+        // If there are deleted values then the constraints must be updated
+        constraintsElimination(ioManager.clone())?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,65 @@
+use super::CandidateSet::candidateSet;
+use super::Units::{sudokuUnit, allUnits};
+
+// Strong link (conjugate pair) of a value: the value is possible only in the two cells of the unit, so one of them
+// must have the value
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct strongLink {
+    pub value: i8,
+    pub unit: sudokuUnit,
+    pub first: (usize, usize),
+    pub second: (usize, usize)
+}
+
+impl strongLink {
+    // Check if the given cell is one of the two cells of the link
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        return self.first == cell || self.second == cell;
+    }
+
+    // Return the cell of the link different from the given one, which must be contained in the link
+    pub fn other(&self, cell: (usize, usize)) -> (usize, usize) {
+        if self.first == cell {
+            return self.second;
+        }
+        return self.first;
+    }
+}
+
+// Index of the strong links of every value of a sudoku. It's built from a copy of the sudoku, so it must be built
+// again after the deletion of some values
+pub struct strongLinkIndex {
+    // The links of the value n are saved in the position n
+    links: [Vec<strongLink>; 10]
+}
+
+impl strongLinkIndex {
+    // Find the strong links of every unit, the values with a definitive position in the unit are ignored
+    pub fn new(sudokuCopy: &[[candidateSet; 9]; 9]) -> strongLinkIndex {
+        let mut links: [Vec<strongLink>; 10] = Default::default();
+        for unit in allUnits() {
+            for value in candidateSet::all() {
+                let validCells: Vec<(usize, usize)> = unit.cells().iter().filter(|&&(i, j)| sudokuCopy[i][j].contains(value)).cloned().collect();
+                if validCells.len() == 2 && validCells.iter().all(|&(i, j)| sudokuCopy[i][j].len() > 1) {
+                    links[value as usize].push(strongLink{value, unit, first: validCells[0], second: validCells[1]});
+                }
+            }
+        }
+        return strongLinkIndex{links};
+    }
+
+    // Return the strong links of the given value
+    pub fn linksOfValue(&self, value: i8) -> &[strongLink] {
+        return &self.links[value as usize];
+    }
+
+    // Return the strong links of the given value that contain the given cell
+    pub fn linksOfCell(&self, value: i8, cell: (usize, usize)) -> Vec<strongLink> {
+        return self.links[value as usize].iter().filter(|link| link.contains(cell)).cloned().collect();
+    }
+
+    // Check if there is a strong link of the given value between the two cells
+    pub fn areLinked(&self, value: i8, first: (usize, usize), second: (usize, usize)) -> bool {
+        return self.links[value as usize].iter().any(|link| link.contains(first) && link.contains(second));
+    }
+}
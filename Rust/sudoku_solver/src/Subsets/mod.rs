@@ -0,0 +1,333 @@
+extern crate crossbeam;
+use crossbeam::crossbeam_channel::bounded;
+use std::sync::{Arc, RwLock, Mutex};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, unitsOfKind, cellName};
+use super::Deductions::deduction;
+use super::ConstraintsElimination::constraintsElimination;
+
+// Kinds of subsets: a naked subset is a group of n cells of a unit whose possible values are only n, a hidden subset
+// is a group of n values of a unit which are possible only in n cells
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum subsetKind {
+    Naked, Hidden
+}
+
+// Find all the naked pairs of rows, columns and boxes
+pub fn findNakedPairs(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findSubsets(ioManager, subsetKind::Naked, 2);
+}
+
+// Find all the naked triples of rows, columns and boxes
+pub fn findNakedTriples(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findSubsets(ioManager, subsetKind::Naked, 3);
+}
+
+// Find all the naked quads of rows, columns and boxes
+pub fn findNakedQuads(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findSubsets(ioManager, subsetKind::Naked, 4);
+}
+
+// Find all the hidden pairs of rows, columns and boxes
+pub fn findHiddenPairs(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findSubsets(ioManager, subsetKind::Hidden, 2);
+}
+
+// Find all the hidden triples of rows, columns and boxes
+pub fn findHiddenTriples(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findSubsets(ioManager, subsetKind::Hidden, 3);
+}
+
+// Find all the hidden quads of rows, columns and boxes
+pub fn findHiddenQuads(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return findSubsets(ioManager, subsetKind::Hidden, 4);
+}
+
+// Find all the subsets of the given kind and size in rows, columns and boxes, until there are no more values to delete
+pub fn findSubsets(ioManager: Arc<RwLock<sudokuIOManager>>, subset: subsetKind, size: i8) -> Result<(), sudokuError> {
+    // Boolean to check if there are deleted values in the following threads
+    let mut updates = true;
+
+    // Channel used by the threads to communicate if there is at least one value modified
+    let (channelSender, channelReceiver) = bounded(unitKind::all().len());
+    let channelSender = Arc::new(Mutex::new(channelSender));
+
+    // While there is at least one thread that updates one or more values, the threads must be executed again
+    while updates {
+        crossbeam::scope(|scope| {
+            // Start a thread for every kind of unit
+            for &kind in unitKind::all().iter() {
+                let manager = ioManager.clone();
+                let channelSenderClone = channelSender.clone();
+                scope.spawn(move |_var| channelSenderClone.lock().unwrap().send(findUnitsSubsets(manager, kind, subset, size)));
+            }
+        }).unwrap();
+
+        // Check the response of every thread
+        updates = false;
+        for _kind in unitKind::all().iter() {
+            if channelReceiver.recv()?? {
+                updates = true;
+            }
+        }
+
+        if updates {
+            // If there are deleted values then the constraints must be updated
+            constraintsElimination(ioManager.clone())?;
+            // After the elimination of the values there could be smaller subsets
+            if size > 2 {
+                findSubsets(ioManager.clone(), subset, size - 1)?;
+            }
+        }
+    }
+    return Ok(());
+}
+
+// Find the subsets of the given kind and size in every unit of the given kind, updates signals if there are deleted values
+pub fn findUnitsSubsets(ioManager: Arc<RwLock<sudokuIOManager>>, kind: unitKind, subset: subsetKind, size: i8) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for unit in unitsOfKind(kind) {
+        let cells = unit.cells();
+        let unitUpdates = match subset {
+            subsetKind::Naked => findUnitNakedSubsets(&ioManager, &sudokuCopy, &cells, size)?,
+            subsetKind::Hidden => findUnitHiddenSubsets(&ioManager, &sudokuCopy, &cells, size)?
+        };
+        if unitUpdates {
+            updates = true;
+        }
+    }
+    return Ok(updates);
+}
+
+// Find the groups of size cells of the unit (row, column or box) given in input which have only size possible values,
+// and delete these values from the other cells of the unit. Return true if there are deleted values
+pub fn findUnitNakedSubsets(ioManager: &Arc<RwLock<sudokuIOManager>>, sudokuCopy: &[[candidateSet; 9]; 9], cells: &[(usize, usize)], size: i8) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    // Save the index of every cell with a number of elements between two and the size of the subset, in order to
+    // minimize the number of analyzed cells
+    let mut validCellsPosition = Vec::new();
+    for (position, &(i, j)) in cells.iter().enumerate() {
+        let valuesNumber = sudokuCopy[i][j].len() as i8;
+        if valuesNumber > 1 && valuesNumber <= size {
+            validCellsPosition.push(position as i8);
+        }
+    }
+    // If there are less cells than the size of the subset, there can't be naked subsets in this unit
+    if validCellsPosition.len() as i8 >= size {
+        // Find the combinations of the cells
+        for combination in getCombinations(validCellsPosition, size, 0) {
+            // Get the union of the possible values of the cells of the combination
+            let mut values = candidateSet::new();
+            for &position in &combination {
+                let (i, j) = cells[position as usize];
+                values = values.union(sudokuCopy[i][j]);
+            }
+            // If the size of the union is the same of the combination then the cells are a naked subset, so these
+            // values can be deleted from the other cells of the unit
+            if values.len() as i8 == size {
+                let subsetCells: Vec<(usize, usize)> = combination.iter().map(|&position| cells[position as usize]).collect();
+                let mut nakedSubset = deduction::new(&subsetName(subsetKind::Naked, size), format!("values {} in {}", values, cellsNames(&subsetCells)));
+                for (position, &(i, j)) in cells.iter().enumerate() {
+                    if !combination.contains(&(position as i8)) && checkSliceElement(sudokuCopy[i][j], values) {
+                        for value in sudokuCopy[i][j].intersection(values) {
+                            nakedSubset.addElimination(i, j, value);
+                        }
+                    }
+                }
+                if !nakedSubset.eliminations.is_empty() {
+                    ioManager.read().unwrap().ApplyDeduction(nakedSubset)?;
+                    updates = true;
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Find the groups of size values which are possible only in the same size cells of the unit (row, column or box) given
+// in input, and delete the other possible values of these cells. Return true if there are deleted values
+pub fn findUnitHiddenSubsets(ioManager: &Arc<RwLock<sudokuIOManager>>, sudokuCopy: &[[candidateSet; 9]; 9], cells: &[(usize, usize)], size: i8) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    // For every value save the mask of the cells of the unit in which it is possible (the bit n is set if the cell
+    // in position n is valid), and select only the values that can be part of a hidden subset of this size
+    let mut valuesPositions = [0u16; 10];
+    let mut validValues = Vec::new();
+    for value in candidateSet::all() {
+        for (position, &(i, j)) in cells.iter().enumerate() {
+            if sudokuCopy[i][j].contains(value) {
+                valuesPositions[value as usize] |= 1 << position;
+            }
+        }
+        let positionsNumber = valuesPositions[value as usize].count_ones() as i8;
+        if positionsNumber > 1 && positionsNumber <= size {
+            validValues.push(value);
+        }
+    }
+    // If there are less values than the size of the subset, there can't be hidden subsets in this unit
+    if validValues.len() as i8 >= size {
+        // Find the combinations of the values
+        for combination in getCombinations(validValues, size, 0) {
+            // Get the union of the cells in which the values of the combination are possible
+            let mut positions = 0u16;
+            for &value in &combination {
+                positions |= valuesPositions[value as usize];
+            }
+            // If the size of the union is the same of the combination then the values are a hidden subset, so the
+            // other values of these cells can be deleted
+            if positions.count_ones() as i8 == size {
+                let subsetValues = candidateSet::fromValues(&combination);
+                let subsetCells: Vec<(usize, usize)> = cells.iter().enumerate().filter(|&(position, _cell)| positions & (1 << position) != 0).map(|(_position, &cell)| cell).collect();
+                let mut hiddenSubset = deduction::new(&subsetName(subsetKind::Hidden, size), format!("values {} only in {}", subsetValues, cellsNames(&subsetCells)));
+                for &(i, j) in subsetCells.iter() {
+                    for value in sudokuCopy[i][j].difference(subsetValues) {
+                        hiddenSubset.addElimination(i, j, value);
+                    }
+                }
+                if !hiddenSubset.eliminations.is_empty() {
+                    ioManager.read().unwrap().ApplyDeduction(hiddenSubset)?;
+                    updates = true;
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Return the name of the subsets of the given kind and size, for example "Naked Pair"
+fn subsetName(subset: subsetKind, size: i8) -> String {
+    let kindName = if subset == subsetKind::Naked { "Naked" } else { "Hidden" };
+    let sizeName = match size {
+        2 => "Pair",
+        3 => "Triple",
+        _ => "Quad"
+    };
+    return format!("{} {}", kindName, sizeName);
+}
+
+// Return the names of the given cells, separated by spaces
+fn cellsNames(cells: &[(usize, usize)]) -> String {
+    return cells.iter().map(|&(i, j)| cellName(i, j)).collect::<Vec<String>>().join(" ");
+}
+
+// Utility functions for combinations
+pub fn getCombinations(arr: Vec<i8>, leng: i8, startPosition: i8) -> Vec<Vec<i8>> {
+    let mut result: Vec<i8> = Vec::new();
+    for _i in 0..leng {
+        result.push(0);
+    }
+    return combinations(&arr, leng, startPosition, &mut result);
+}
+
+// Return all possible combinations of length leng of the elements in arr
+fn combinations(arr: &[i8], leng: i8, startPosition: i8, result: &mut Vec<i8>) -> Vec<Vec<i8>> {
+    let mut finalResult = Vec::new();
+    if leng == 0 {
+        // In result there is one combination, which copied and saved in finalResult
+        finalResult.push(result.to_vec());
+        return finalResult;
+    }
+    //let numb = arr.len() as i8 - leng + 1;
+    for i in startPosition..(arr.len() as i8 - leng + 1) {
+        let resleng = result.len();
+        result[resleng - leng as usize] = arr[i as usize];
+        // Append to finalResult the combinations obtained with the recursive calls of this function
+        finalResult.append(&mut combinations(arr, leng-1, i+1, result));
+    }
+    return finalResult;
+}
+
+// Check if the cell has more than one possible value and at least one of them is contained in the given values
+pub fn checkSliceElement(slice: candidateSet, values: candidateSet) -> bool {
+    return slice.len() > 1 && !slice.intersection(values).is_empty();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Units::sudokuUnit;
+    use crate::TestGrids::{fullGrid, removeValue, managerOf, eliminationsOf, techniquesOf};
+
+    #[test]
+    fn nakedPairOfRow() {
+        // The cells r1c1 and r1c2 have only the values 1 and 2, which can be deleted from the rest of the row
+        let mut grid = fullGrid();
+        grid[0][0] = candidateSet::fromValues(&[1, 2]);
+        grid[0][1] = candidateSet::fromValues(&[1, 2]);
+        let manager = managerOf(grid);
+        assert!(findUnitsSubsets(manager.clone(), unitKind::Row, subsetKind::Naked, 2).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Naked Pair"]);
+        let expected: Vec<(usize, usize, i8)> = (2..9).flat_map(|j| vec![(0, j, 1), (0, j, 2)]).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn hiddenTripleOfColumn() {
+        // In the column 4 the values 3, 4 and 5 are possible only in r1c4, r5c4 and r9c4, so their other values can be deleted
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = [1, 2, 3, 5, 6, 7].iter().map(|&i| (i, 3)).collect();
+        for value in 3..6 {
+            removeValue(&mut grid, value, &otherCells);
+        }
+        let manager = managerOf(grid);
+        assert!(!findUnitsSubsets(manager.clone(), unitKind::Row, subsetKind::Hidden, 3).unwrap());
+        assert!(findUnitsSubsets(manager.clone(), unitKind::Column, subsetKind::Hidden, 3).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Hidden Triple"]);
+        let expected: Vec<(usize, usize, i8)> = [0, 4, 8].iter().flat_map(|&i| [1, 2, 6, 7, 8, 9].iter().map(move |&value| (i, 3, value))).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn hiddenPairOfBox() {
+        // In the box 5 the values 6 and 7 are possible only in r4c4 and r6c6, so their other values can be deleted
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = sudokuUnit::new(unitKind::Box, 4).cells().iter().filter(|&&cell| cell != (3, 3) && cell != (5, 5)).cloned().collect();
+        removeValue(&mut grid, 6, &otherCells);
+        removeValue(&mut grid, 7, &otherCells);
+        let manager = managerOf(grid);
+        assert!(findUnitsSubsets(manager.clone(), unitKind::Box, subsetKind::Hidden, 2).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Hidden Pair"]);
+        let expected: Vec<(usize, usize, i8)> = [(3, 3), (5, 5)].iter().flat_map(|&(i, j)| [1, 2, 3, 4, 5, 8, 9].iter().map(move |&value| (i, j, value))).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn nakedQuadOfColumn() {
+        // The cells r1c9, r2c9, r3c9 and r4c9 have only the values 1, 2, 3 and 4, which can be deleted from the rest of the column
+        let mut grid = fullGrid();
+        grid[0][8] = candidateSet::fromValues(&[1, 2]);
+        grid[1][8] = candidateSet::fromValues(&[2, 3]);
+        grid[2][8] = candidateSet::fromValues(&[3, 4]);
+        grid[3][8] = candidateSet::fromValues(&[1, 4]);
+        let manager = managerOf(grid);
+        assert!(!findUnitsSubsets(manager.clone(), unitKind::Column, subsetKind::Naked, 3).unwrap());
+        assert!(findUnitsSubsets(manager.clone(), unitKind::Column, subsetKind::Naked, 4).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Naked Quad"]);
+        let expected: Vec<(usize, usize, i8)> = (4..9).flat_map(|i| (1..5).map(move |value| (i, 8, value))).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn hiddenQuadOfRow() {
+        // In the row 9 the values 5, 6, 7 and 8 are possible only in r9c1, r9c3, r9c5 and r9c7, so their other values can be deleted
+        let mut grid = fullGrid();
+        let otherCells: Vec<(usize, usize)> = [1, 3, 5, 7, 8].iter().map(|&j| (8, j)).collect();
+        for value in 5..9 {
+            removeValue(&mut grid, value, &otherCells);
+        }
+        let manager = managerOf(grid);
+        assert!(findUnitsSubsets(manager.clone(), unitKind::Row, subsetKind::Hidden, 4).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["Hidden Quad"]);
+        let expected: Vec<(usize, usize, i8)> = [0, 2, 4, 6].iter().flat_map(|&j| [1, 2, 3, 4, 9].iter().map(move |&value| (8, j, value))).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+}
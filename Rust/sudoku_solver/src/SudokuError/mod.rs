@@ -0,0 +1,69 @@
+extern crate crossbeam;
+use crossbeam::crossbeam_channel::{RecvError, SendError};
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+// Errors returned by the public functions of the solver
+#[derive(Debug)]
+pub enum sudokuError {
+    // The file of the sudoku can't be read
+    Io(io::Error),
+    // The sudoku contains a character that is neither a number nor a placeholder, line and column start from 1
+    MalformedGrid{line: usize, column: usize, character: char},
+    // The sudoku doesn't contain exactly 81 cells, the value is the number of cells found
+    WrongCellCount(usize),
+    // The definitive values given in input break the rules
+    ContradictoryGivens,
+    // The sudoku doesn't have a solution
+    NoSolution,
+    // The search of the solution has been stopped before finding it
+    Timeout,
+    // A channel used by the threads of the solver has been closed while it was still needed
+    ChannelClosed,
+    // A thread of the solver has panicked
+    ThreadPanicked
+}
+
+impl fmt::Display for sudokuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            sudokuError::Io(error) => write!(f, "Cannot read the sudoku: {}", error),
+            sudokuError::MalformedGrid{line, column, character} =>
+                write!(f, "Unexpected character '{}' at line {}, column {}", character, line, column),
+            sudokuError::WrongCellCount(count) => write!(f, "The sudoku contains {} cells instead of 81", count),
+            sudokuError::ContradictoryGivens => write!(f, "The definitive values of the sudoku break the rules"),
+            sudokuError::NoSolution => write!(f, "The sudoku doesn't have a solution"),
+            sudokuError::Timeout => write!(f, "The time limit has been reached before finding a solution"),
+            sudokuError::ChannelClosed => write!(f, "A channel of the solver has been closed unexpectedly"),
+            sudokuError::ThreadPanicked => write!(f, "A thread of the solver has stopped unexpectedly")
+        }
+    }
+}
+
+impl Error for sudokuError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            sudokuError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for sudokuError {
+    fn from(error: io::Error) -> sudokuError {
+        return sudokuError::Io(error);
+    }
+}
+
+impl From<RecvError> for sudokuError {
+    fn from(_error: RecvError) -> sudokuError {
+        return sudokuError::ChannelClosed;
+    }
+}
+
+impl<T> From<SendError<T>> for sudokuError {
+    fn from(_error: SendError<T>) -> sudokuError {
+        return sudokuError::ChannelClosed;
+    }
+}
@@ -1,100 +1,118 @@
 extern crate crossbeam;
-use crossbeam::crossbeam_channel::{Receiver, Sender, Select, unbounded};
+use crossbeam::crossbeam_channel::{Receiver, Sender, bounded, unbounded};
 use super::SudokuManager::sudoku;
-use std::sync::{Arc, RwLock};
-use std::thread;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Deductions::deduction;
+use std::sync::{Arc, RwLock, Mutex};
+use std::thread::{self, JoinHandle};
 
-// Contain the variables needed to delete a specific value from a given cell
-struct sudokuDeleteNumber {
-    row:i8, column:i8, value:i8
+// Commands executed by the thread of the sudokuIOManager, in the same order in which they are sent
+enum sudokuCommand {
+    // Delete a specific value from a given cell
+    Delete{row:i8, column:i8, value:i8},
+    // Send a copy of the sudoku in the given channel, after the execution of every previous deletion
+    Snapshot(Sender<[[candidateSet; 9]; 9]>),
+    // Terminate the thread
+    Shutdown
 }
 
-// Manager of sudoku output channels
+// Manager of the sudoku, every modification is executed by its own thread, which is started by the constructor
+// and terminated when the manager is destroyed
 pub struct sudokuIOManager {
-    pub sudokuVar: sudoku,
-    deleteSender: Sender<sudokuDeleteNumber>,
-    deleteReceiver: Receiver<sudokuDeleteNumber>,
-    pub requestSudokuSender: Sender<bool>,
-    pub requestSudokuReceiver: Receiver<bool>,
-    pub sendSudokuSender: Sender<[[Vec<i8>; 9]; 9]>,
-    pub sendSudokuReceiver: Receiver<[[Vec<i8>; 9]; 9]>
+    sudokuVar: Arc<RwLock<sudoku>>,
+    // Deductions applied to the sudoku, in the order in which they have been found
+    deductions: Mutex<Vec<deduction>>,
+    commandSender: Sender<sudokuCommand>,
+    thread: Option<JoinHandle<()>>
 }
 
 impl sudokuIOManager {
     pub fn new(sudokuVar: sudoku) -> sudokuIOManager {
-        let (deleteSender, deleteReceiver) = unbounded();
-        let (requestSudokuSender, requestSudokuReceiver) = unbounded();
-        let (sendSudokuSender, sendSudokuReceiver) = unbounded();
-        return sudokuIOManager{sudokuVar, deleteSender, deleteReceiver,
-            requestSudokuSender, requestSudokuReceiver, sendSudokuSender, sendSudokuReceiver
+        let (commandSender, commandReceiver) = unbounded();
+        let sudokuVar = Arc::new(RwLock::new(sudokuVar));
+        let sudokuVarClone = sudokuVar.clone();
+        let thread = thread::spawn(move || Run(sudokuVarClone, commandReceiver));
+        return sudokuIOManager{sudokuVar, deductions: Mutex::new(Vec::new()), commandSender, thread: Some(thread)};
+    }
+
+    // This method adds the data about the number to delete to the command channel
+    pub fn DeleteNumber(&self, i:i8, j:i8, value:i8) -> Result<(), sudokuError> {
+        self.commandSender.send(sudokuCommand::Delete{row: i, column: j, value})?;
+        return Ok(());
+    }
+
+    // Delete every value of the deduction and save it, in order to explain how the sudoku has been solved
+    pub fn ApplyDeduction(&self, deductionVar: deduction) -> Result<(), sudokuError> {
+        for deleted in deductionVar.eliminations.iter() {
+            self.DeleteNumber(deleted.row as i8, deleted.column as i8, deleted.value)?;
         }
+        self.deductions.lock().unwrap().push(deductionVar);
+        return Ok(());
     }
 
-    // This method adds the data about the number to delete to the delete channel
-    pub fn DeleteNumber(&self, i:i8, j:i8, value:i8) {
-        self.deleteSender.send(sudokuDeleteNumber{row: i, column: j, value: value}).unwrap();
+    // Return a copy of the deductions applied until now
+    pub fn GetDeductions(&self) -> Vec<deduction> {
+        return self.deductions.lock().unwrap().clone();
     }
 
-    // This method returns the slice of the cell in the position given in input.
+    // This method returns the possible values of the cell in the position given in input.
     // The consequence is that every thread will work with the IOManager instead of the sudoku
-    pub fn GetSlice(&self, i:i8, j:i8) -> Vec<i8> {
-        return self.sudokuVar.getCellValue(i, j);
+    pub fn GetSlice(&self, i:i8, j:i8) -> candidateSet {
+        return self.sudokuVar.read().unwrap().getCellValue(i, j);
     }
 
     // This method check if the given value is contained in the cell in position i and j
     pub fn CheckNumber(&self, i:i8, j:i8, value:i8) -> bool {
-        return self.sudokuVar.checkCellValue(i, j, value);
+        return self.sudokuVar.read().unwrap().checkCellValue(i, j, value);
     }
 
-    // Return the sudoku for the brute force methods
-    pub fn GetSudoku(&self) -> [[Vec<i8>; 9]; 9] {
-        self.requestSudokuSender.send(true).unwrap();
-        return self.sendSudokuReceiver.recv().unwrap();
+    // Return a copy of the sudoku, in which every deletion requested before this call has been executed
+    pub fn GetSudoku(&self) -> Result<[[candidateSet; 9]; 9], sudokuError> {
+        let (sendSudokuSender, sendSudokuReceiver) = bounded(1);
+        self.commandSender.send(sudokuCommand::Snapshot(sendSudokuSender))?;
+        return Ok(sendSudokuReceiver.recv()?);
+    }
+
+    // Wait until every deletion requested before this call has been executed
+    pub fn WaitDeletions(&self) -> Result<(), sudokuError> {
+        self.GetSudoku()?;
+        return Ok(());
     }
 
     pub fn PrintSudoku(&self) {
-        self.sudokuVar.printSudoku();
+        self.sudokuVar.read().unwrap().printSudoku();
     }
 }
 
-// Run method of sudokuIOManager, for every value to delete check if the number exists (because of the
-// execution of multiple concurrent threads)
-pub fn Run(ioManagerPointer: Arc<RwLock<sudokuIOManager>>) {
-    thread::spawn(move || {
-        loop {
-            let ioManager = ioManagerPointer.read().unwrap();
-            let srRes = ioManager.requestSudokuReceiver.try_recv();
-            // Check if there is a request of a copy of the sudoku
-            if srRes.is_ok() {
-                let res = srRes.unwrap();
+impl Drop for sudokuIOManager {
+    // Terminate the thread of the manager, the deletions requested before are executed anyway
+    fn drop(&mut self) {
+        // If the thread is already terminated the channel is closed, but there is nothing else to do
+        let _ = self.commandSender.send(sudokuCommand::Shutdown);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
 
-                // Create and send the sudoku copy
-                let mut matrixCopy: [[Vec<i8>; 9]; 9] = Default::default();
-                for i in 0..9 {
-                    for j in 0..9 {
-                        for z in 0..ioManager.sudokuVar.sudokuMatrix[i][j].len() {
-                            matrixCopy[i][j].push(ioManager.sudokuVar.sudokuMatrix[i][j][z]);
-                        }
-                    }
-                }
-                ioManager.sendSudokuSender.send(matrixCopy).unwrap();
-            } else {
-                // Release the read lock and try to take the write one
-                drop(ioManager);
-                let result = ioManagerPointer.try_write();
-                if result.is_ok() {
-                    let mut ioManager = result.unwrap();
-                    // Check if there is an element to delete
-                    let recRes = ioManager.deleteReceiver.try_recv();
-                    if recRes.is_ok() {
-                        let res = recRes.unwrap();
-                        // Delete the element
-                        if ioManager.sudokuVar.checkCellValue(res.row, res.column, res.value) {
-                            ioManager.sudokuVar.deleteCellValue(res.row, res.column, res.value);
-                        }
-                    }
+// Run method of sudokuIOManager, waits the commands and executes them until the shutdown. For every value to delete
+// check if the number exists (because of the execution of multiple concurrent threads)
+fn Run(sudokuVar: Arc<RwLock<sudoku>>, commandReceiver: Receiver<sudokuCommand>) {
+    // The loop ends also if the manager doesn't exist anymore, because the channel is closed
+    for command in commandReceiver.iter() {
+        match command {
+            sudokuCommand::Delete{row, column, value} => {
+                let mut sudokuVar = sudokuVar.write().unwrap();
+                if sudokuVar.checkCellValue(row, column, value) {
+                    sudokuVar.deleteCellValue(row, column, value);
                 }
             }
+            sudokuCommand::Snapshot(sendSudokuSender) => {
+                // If the requester doesn't wait the copy anymore the channel is closed, so the error can be ignored
+                let _ = sendSudokuSender.send(sudokuVar.read().unwrap().sudokuMatrix);
+            }
+            sudokuCommand::Shutdown => break
         }
-    });
-}
\ No newline at end of file
+    }
+}
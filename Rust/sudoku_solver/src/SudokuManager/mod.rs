@@ -1,69 +1,74 @@
 use std::fs;
+use super::SudokuError::sudokuError;
+use super::CandidateSet::candidateSet;
 
+#[derive(Clone, Copy)]
 pub struct sudoku {
-    // The first 2 dimensions are the rows and the columns of the sudoku, the third contains all the possible values of the cell
-    pub sudokuMatrix: [[Vec<i8>; 9]; 9]
+    // The 2 dimensions are the rows and the columns of the sudoku, every cell contains all the possible values
+    pub sudokuMatrix: [[candidateSet; 9]; 9]
 }
 
 impl sudoku {
     // Delete a specific value from a specific cell
     pub fn deleteCellValue (&mut self, i:i8, j:i8, value:i8) {
-        deleteSliceElement(&mut self.sudokuMatrix[i as usize][j as usize], value)
+        self.sudokuMatrix[i as usize][j as usize].remove(value);
     }
 
-    // Get the slice of a specific cell
-    pub fn getCellValue(&self, i:i8, j:i8) -> Vec<i8> {
-        return self.sudokuMatrix[i as usize][j as usize].clone();
+    // Get the possible values of a specific cell
+    pub fn getCellValue(&self, i:i8, j:i8) -> candidateSet {
+        return self.sudokuMatrix[i as usize][j as usize];
     }
 
     // Check if the cell has more than 2 elements and contains the value given in input
     pub fn checkCellValue(&self, i:i8, j:i8, value:i8) -> bool {
-        return self.sudokuMatrix[i as usize][j as usize].len() > 1 && contains(&self.sudokuMatrix[i as usize][j as usize], value);
+        return self.sudokuMatrix[i as usize][j as usize].len() > 1 && self.sudokuMatrix[i as usize][j as usize].contains(value);
     }
 
     // Sudoku constructor, which saves a sudokuMatrix in a new container
-    pub fn newContainer(sudokuMatrix: [[Vec<i8>; 9]; 9]) -> sudoku {
+    pub fn newContainer(sudokuMatrix: [[candidateSet; 9]; 9]) -> sudoku {
         return sudoku{sudokuMatrix};
     }
 
     // Sudoku constructor, loads the file from the path given in input and initialize the sudoku matrix with its content
-    pub fn new(path:String) -> sudoku {
-        let matrix: [[Vec<i8>; 9]; 9] = Default::default();
-        let mut sudokuVar = sudoku{sudokuMatrix: matrix};
-
+    pub fn new(path:String) -> Result<sudoku, sudokuError> {
         // Load file in a string
-        let fileString = fs::read_to_string(path).unwrap();
+        let fileString = fs::read_to_string(path)?;
+        return sudoku::fromString(fileString);
+    }
 
-        // Row (i) and column (j) indices
-        let mut i = 0;
-        let mut j = 0;
+    // Sudoku constructor, initialize the sudoku matrix with the content of the string given in input.
+    // Numbers from 1 to 9 are definitive values, while '_', '.' and '0' are placeholders for the values to find
+    pub fn fromString(fileString:String) -> Result<sudoku, sudokuError> {
+        let matrix: [[candidateSet; 9]; 9] = Default::default();
+        let mut sudokuVar = sudoku{sudokuMatrix: matrix};
+
+        // Number of cells found, used to get the row and column indices
+        let mut cellsNumber = 0;
         // Check every file character
-        for z in 0..fileString.len() {
-            // Check if the character is a number
-            if isNumeric(fileString.chars().nth(z).unwrap()) {
-                // Save the value
-                let mut vec = Vec::new();
-                vec.push(fileString.chars().nth(z).unwrap().to_digit(10).unwrap() as i8);
-                sudokuVar.sudokuMatrix[i][j] = vec;
-                // Increment column index, if the row is finished then reset j and increment i
-                j += 1;
-                if j == 9 {
-                    i += 1;
-                    j = 0;
-                }
-            } else if fileString.chars().nth(z).unwrap() == '_' {
-                // The character is a placeholder for a value to find, so the corresponding vector will contain every possible number
-                let possibleNumbersVec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-                sudokuVar.sudokuMatrix[i][j] = possibleNumbersVec;
-                // Increment column index, if the row is finished then reset j and increment i
-                j += 1;
-                if j == 9 {
-                    i += 1;
-                    j = 0;
+        for (line, lineString) in fileString.lines().enumerate() {
+            for (column, character) in lineString.chars().enumerate() {
+                let cellSlice = if isNumeric(character) && character != '0' {
+                    // Save the value
+                    candidateSet::single(character.to_digit(10).unwrap() as i8)
+                } else if character == '_' || character == '.' || character == '0' {
+                    // The character is a placeholder for a value to find, so the corresponding set will contain every possible number
+                    candidateSet::all()
+                } else if character.is_whitespace() {
+                    continue;
+                } else {
+                    return Err(sudokuError::MalformedGrid{line: line + 1, column: column + 1, character});
+                };
+                // Save the cell only if it is inside the matrix, the exceeding ones are just counted
+                if cellsNumber < 81 {
+                    sudokuVar.sudokuMatrix[cellsNumber / 9][cellsNumber % 9] = cellSlice;
                 }
+                cellsNumber += 1;
             }
         }
-        return sudokuVar;
+        if cellsNumber != 81 {
+            return Err(sudokuError::WrongCellCount(cellsNumber));
+        }
+        return Ok(sudokuVar);
     }
 
     // This function contains the general structure of sudoku printing, and uses some utility methods
@@ -84,12 +89,12 @@ impl sudoku {
         // Iterate for every column of the sudoku matrix
         for j in 0..9 {
             // Save the values of the analyzed cell in a variable
-            let sudokuCell = self.sudokuMatrix[row as usize][j].clone();
+            let sudokuCell = self.sudokuMatrix[row as usize][j];
             // Check if the value has already been found or not
             if sudokuCell.len() > 1 {
                 for z in 1..4 {
                     // If the cell contains z, add the number to the list of the values to print, otherwise add -1 to print a space
-                    if contains(&sudokuCell, z) {
+                    if sudokuCell.contains(z) {
                         valuesList.push(z);
                     } else {
                         valuesList.push(-1);
@@ -110,12 +115,12 @@ impl sudoku {
         // Iterate for every column of the sudoku matrix
         for j in 0..9 {
             // Save the values of the analyzed cell in a variable
-            let sudokuCell = self.sudokuMatrix[row as usize][j].clone();
+            let sudokuCell = self.sudokuMatrix[row as usize][j];
             // Check if the value has already been found or not
             if sudokuCell.len() > 1 {
                 for z in 4..7 {
                     // If the cell contains z, add the number to the list of the values to print, otherwise add -1 to print a space
-                    if contains(&sudokuCell, z) {
+                    if sudokuCell.contains(z) {
                         valuesList.push(z);
                     } else {
                         valuesList.push(-1);
@@ -125,7 +130,7 @@ impl sudoku {
                 // The line of this cell will contain only one value in the middle, so two -1 are added to print spaces
                 valuesList.push(-1);
                 // The value is incremented of 10 to signal that is definitive, and not one of possible values
-                valuesList.push(self.sudokuMatrix[row as usize][j][0] + 10);
+                valuesList.push(sudokuCell.first().unwrap() + 10);
                 valuesList.push(-1);
             }
             // Signal that the next numbers are referred to a different cell
@@ -137,12 +142,12 @@ impl sudoku {
         // Iterate for every column of the sudoku matrix
         for j in 0..9 {
             // Save the values of the analyzed cell in a variable
-            let sudokuCell = self.sudokuMatrix[row as usize][j].clone();
+            let sudokuCell = self.sudokuMatrix[row as usize][j];
             // Check if the value has already been found or not
             if sudokuCell.len() > 1 {
                 for z in 7..10 {
                     // If the cell contains z, add the number to the list of the values to print, otherwise add -1 to print a space
-                    if contains(&sudokuCell, z) {
+                    if sudokuCell.contains(z) {
                         valuesList.push(z);
                     } else {
                         valuesList.push(-1)
@@ -213,23 +218,7 @@ impl sudoku {
     }
 }
 
-// Utility function, check if a slice contains a specific value
-pub fn contains(slice: &Vec<i8>, v: i8) -> bool {
-    return slice.contains(&v);
-}
-
-// Utility function, delete a specific value from a slice
-pub fn deleteSliceElement(slice: &mut Vec<i8>, v: i8) {
-    let pos = findSliceElement(&slice, v);
-    slice.remove(pos);
-}
-
-// Utility function, return the position of a specific value in a slice
-pub fn findSliceElement(slice: &Vec<i8>, v: i8) -> usize {
-    return slice.iter().position(|&r| r == v).unwrap();
-}
-
 // Utility function, check if a character is a number
 pub fn isNumeric(b: char) -> bool {
-    return b.is_digit(10);
+    return b.is_ascii_digit();
 }
\ No newline at end of file
@@ -0,0 +1,240 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use super::SudokuManager::sudoku;
+use super::SudokuError::sudokuError;
+use super::SudokuIOManager::sudokuIOManager;
+use super::CheckSudokuMethods::{checkSudokuIsComplete, checkSudokuCorrectness};
+use super::ConstraintsElimination::constraintsElimination;
+use super::HiddenSingles::findHiddenSingles;
+use super::Subsets::{findSubsets, subsetKind};
+use super::LockedCandidates::{findPointingCandidates, findClaimingCandidates};
+use super::Fish::{findFish, findFinnedFish};
+use super::Wings::{findXYWings, findXYZWings};
+use super::LinkPatterns::{linkPattern, findLinkPatterns};
+use super::Coloring::{findColoring, findMedusa};
+use super::Chains::{findXCycles, findAlternatingInferenceChains, DEFAULT_CHAIN_LENGTH};
+use super::Uniqueness::{findUniqueRectangles, findBUG};
+use super::AlmostLockedSets::{alsPattern, findAlmostLockedSets};
+use super::SueDeCoq::findSueDeCoq;
+use super::ForcingChains::{forcingKind, findForcingChains, DEFAULT_FORCING_DEPTH};
+use super::Templates::findTemplates;
+use super::Deductions::deduction;
+use super::BruteForceMethods::{bruteForceSolving, bruteForceResult};
+
+// Strategies that can be used by the solver, in the order in which they are executed
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum strategy {
+    ConstraintsElimination, HiddenSingles, Pointing, Claiming, NakedPairs, NakedTriples, HiddenPairs, HiddenTriples, NakedQuads, HiddenQuads,
+    XWing, Swordfish, Jellyfish, FinnedXWing, FinnedSwordfish, FinnedJellyfish, XYWing, XYZWing,
+    Skyscraper, TwoStringKite, EmptyRectangle, WWing, SimpleColoring, MultiColoring, Medusa, XCycle, AIC, AlsXZ, AlsXYWing, DeathBlossom, SueDeCoq, UniqueRectangle, BUG,
+    Templates, TemplatePairs, CellForcingChains, UnitForcingChains, ContradictionChains, BruteForce
+}
+
+impl strategy {
+    // Return every available strategy
+    pub fn all() -> Vec<strategy> {
+        return vec![strategy::ConstraintsElimination, strategy::HiddenSingles, strategy::Pointing, strategy::Claiming,
+                    strategy::NakedPairs, strategy::NakedTriples, strategy::HiddenPairs, strategy::HiddenTriples,
+                    strategy::NakedQuads, strategy::HiddenQuads, strategy::XWing, strategy::Swordfish, strategy::Jellyfish,
+                    strategy::FinnedXWing, strategy::FinnedSwordfish, strategy::FinnedJellyfish, strategy::XYWing, strategy::XYZWing,
+                    strategy::Skyscraper, strategy::TwoStringKite, strategy::EmptyRectangle, strategy::WWing,
+                    strategy::SimpleColoring, strategy::MultiColoring, strategy::Medusa, strategy::XCycle, strategy::AIC,
+                    strategy::AlsXZ, strategy::AlsXYWing, strategy::DeathBlossom, strategy::SueDeCoq,
+                    strategy::UniqueRectangle, strategy::BUG, strategy::Templates, strategy::TemplatePairs,
+                    strategy::CellForcingChains, strategy::UnitForcingChains, strategy::ContradictionChains, strategy::BruteForce];
+    }
+}
+
+// Final state of the sudoku obtained by the solver
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum solveStatus {
+    // Every cell has a definitive value and the rules are respected
+    Solved,
+    // The selected strategies are not enough to find every definitive value
+    Unsolved
+}
+
+// Contain the sudoku obtained by the solver, its state and the deductions found by the strategies that explain them
+pub struct solveResult {
+    pub sudokuVar: sudoku,
+    pub status: solveStatus,
+    pub deductions: Vec<deduction>
+}
+
+// Entry point of the library, runs the selected strategies on a sudoku
+pub struct sudokuSolver {
+    pub strategies: Vec<strategy>,
+    // Maximum duration of the brute force search, without a value the search continues until its end
+    pub timeLimit: Option<Duration>,
+    // Maximum number of links of the chains searched by the X-Cycles and the alternating inference chains
+    pub chainLength: usize,
+    // Allow the strategies that are valid only if the sudoku has a unique solution (Unique Rectangle and BUG+1)
+    pub assumeUniqueness: bool,
+    // Maximum number of propagation steps of the forcing chains
+    pub forcingDepth: usize
+}
+
+impl Default for sudokuSolver {
+    fn default() -> sudokuSolver {
+        return sudokuSolver::new();
+    }
+}
+
+impl sudokuSolver {
+    // Solver constructor, every strategy is used
+    pub fn new() -> sudokuSolver {
+        return sudokuSolver{strategies: strategy::all(), timeLimit: None, chainLength: DEFAULT_CHAIN_LENGTH, assumeUniqueness: false, forcingDepth: DEFAULT_FORCING_DEPTH};
+    }
+
+    // Solver constructor, only the strategies given in input are used
+    pub fn withStrategies(strategies: Vec<strategy>) -> sudokuSolver {
+        return sudokuSolver{strategies, timeLimit: None, chainLength: DEFAULT_CHAIN_LENGTH, assumeUniqueness: false, forcingDepth: DEFAULT_FORCING_DEPTH};
+    }
+
+    // Check if the given strategy has been selected
+    pub fn uses(&self, selected: strategy) -> bool {
+        return self.strategies.contains(&selected);
+    }
+
+    // Delete the invalid constraints, place the hidden singles, find the locked candidates, the naked and hidden subsets,
+    // the fish, the wings, the patterns based on strong links, the coloring, the 3D Medusa, the chains, the almost
+    // locked sets, the Sue de Coq, the uniqueness strategies (if the solution is assumed unique), the templates and the
+    // forcing chains
+    fn runStrategies(&self, ioManagerPointer: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+        if self.uses(strategy::ConstraintsElimination) {
+            constraintsElimination(ioManagerPointer.clone())?;
+        }
+        if self.uses(strategy::HiddenSingles) {
+            findHiddenSingles(ioManagerPointer.clone())?;
+        }
+        if self.uses(strategy::Pointing) {
+            findPointingCandidates(ioManagerPointer.clone())?;
+        }
+        if self.uses(strategy::Claiming) {
+            findClaimingCandidates(ioManagerPointer.clone())?;
+        }
+        // Every subset strategy with its kind and its size, from the smallest to the biggest
+        let subsetStrategies = [
+            (strategy::NakedPairs, subsetKind::Naked, 2), (strategy::NakedTriples, subsetKind::Naked, 3),
+            (strategy::HiddenPairs, subsetKind::Hidden, 2), (strategy::HiddenTriples, subsetKind::Hidden, 3),
+            (strategy::NakedQuads, subsetKind::Naked, 4), (strategy::HiddenQuads, subsetKind::Hidden, 4)
+        ];
+        for &(selected, subset, size) in subsetStrategies.iter() {
+            if self.uses(selected) {
+                findSubsets(ioManagerPointer.clone(), subset, size)?;
+            }
+        }
+        // Every fish strategy with its size
+        let fishStrategies = [(strategy::XWing, 2), (strategy::Swordfish, 3), (strategy::Jellyfish, 4)];
+        for &(selected, size) in fishStrategies.iter() {
+            if self.uses(selected) {
+                findFish(ioManagerPointer.clone(), size)?;
+            }
+        }
+        let finnedFishStrategies = [(strategy::FinnedXWing, 2), (strategy::FinnedSwordfish, 3), (strategy::FinnedJellyfish, 4)];
+        for &(selected, size) in finnedFishStrategies.iter() {
+            if self.uses(selected) {
+                findFinnedFish(ioManagerPointer.clone(), size)?;
+            }
+        }
+        if self.uses(strategy::XYWing) {
+            findXYWings(ioManagerPointer.clone())?;
+        }
+        if self.uses(strategy::XYZWing) {
+            findXYZWings(ioManagerPointer.clone())?;
+        }
+        // The patterns based on strong links are found together, in order to share the links
+        let linkStrategies = [
+            (strategy::Skyscraper, linkPattern::Skyscraper), (strategy::TwoStringKite, linkPattern::TwoStringKite),
+            (strategy::EmptyRectangle, linkPattern::EmptyRectangle), (strategy::WWing, linkPattern::WWing)
+        ];
+        let patterns: Vec<linkPattern> = linkStrategies.iter().filter(|&&(selected, _pattern)| self.uses(selected)).map(|&(_selected, pattern)| pattern).collect();
+        findLinkPatterns(ioManagerPointer.clone(), &patterns)?;
+        findColoring(ioManagerPointer.clone(), self.uses(strategy::SimpleColoring), self.uses(strategy::MultiColoring))?;
+        if self.uses(strategy::Medusa) {
+            findMedusa(ioManagerPointer.clone())?;
+        }
+        if self.uses(strategy::XCycle) {
+            findXCycles(ioManagerPointer.clone(), self.chainLength)?;
+        }
+        if self.uses(strategy::AIC) {
+            findAlternatingInferenceChains(ioManagerPointer.clone(), self.chainLength)?;
+        }
+        // The patterns based on almost locked sets are found together, in order to share the sets
+        let alsStrategies = [
+            (strategy::AlsXZ, alsPattern::XZ), (strategy::AlsXYWing, alsPattern::XYWing), (strategy::DeathBlossom, alsPattern::DeathBlossom)
+        ];
+        let alsPatterns: Vec<alsPattern> = alsStrategies.iter().filter(|&&(selected, _pattern)| self.uses(selected)).map(|&(_selected, pattern)| pattern).collect();
+        findAlmostLockedSets(ioManagerPointer.clone(), &alsPatterns)?;
+        if self.uses(strategy::SueDeCoq) {
+            findSueDeCoq(ioManagerPointer.clone())?;
+        }
+        // The uniqueness strategies would delete valid values from a sudoku with more solutions
+        if self.assumeUniqueness && self.uses(strategy::UniqueRectangle) {
+            findUniqueRectangles(ioManagerPointer.clone())?;
+        }
+        if self.assumeUniqueness && self.uses(strategy::BUG) {
+            findBUG(ioManagerPointer.clone())?;
+        }
+        if self.uses(strategy::Templates) || self.uses(strategy::TemplatePairs) {
+            findTemplates(ioManagerPointer.clone(), self.uses(strategy::TemplatePairs))?;
+        }
+        // The forcing chains are the last logical strategies, used before the brute force
+        let forcingStrategies = [
+            (strategy::ContradictionChains, forcingKind::Contradiction), (strategy::CellForcingChains, forcingKind::Cell),
+            (strategy::UnitForcingChains, forcingKind::Unit)
+        ];
+        let forcingKinds: Vec<forcingKind> = forcingStrategies.iter().filter(|&&(selected, _kind)| self.uses(selected)).map(|&(_selected, kind)| kind).collect();
+        findForcingChains(ioManagerPointer.clone(), &forcingKinds, self.forcingDepth)?;
+        return Ok(());
+    }
+
+    // Run the selected strategies on the sudoku and return the obtained result
+    pub fn solve(&self, sudokuVar: sudoku) -> Result<solveResult, sudokuError> {
+        let ioManager = sudokuIOManager::new(sudokuVar);
+        if !checkSudokuCorrectness(&ioManager)? {
+            return Err(sudokuError::ContradictoryGivens);
+        }
+        let ioManagerPointer = Arc::new(RwLock::new(ioManager));
+
+        // Run the selected strategies until none of them deletes a value, because the deletions of a strategy
+        // can allow the previous ones to find other values
+        let mut previousSudoku = None;
+        loop {
+            let currentSudoku = ioManagerPointer.read().unwrap().GetSudoku()?;
+            if previousSudoku == Some(currentSudoku) {
+                break;
+            }
+            previousSudoku = Some(currentSudoku);
+            self.runStrategies(ioManagerPointer.clone())?;
+        }
+
+        let ioManager = ioManagerPointer.read().unwrap();
+        let deductions = ioManager.GetDeductions();
+        let resultPointer = if !checkSudokuIsComplete(&ioManager) && self.uses(strategy::BruteForce) {
+            // Delete the lock to allow the contraint elimination in bruteForceSolving function
+            drop(ioManager);
+            let newManager = match bruteForceSolving(ioManagerPointer.clone(), self.timeLimit)? {
+                bruteForceResult::Solved(newManager) => *newManager,
+                bruteForceResult::Unsolvable => return Err(sudokuError::NoSolution),
+                bruteForceResult::Cancelled => return Err(sudokuError::Timeout)
+            };
+            Arc::new(RwLock::new(newManager))
+        } else {
+            drop(ioManager);
+            ioManagerPointer
+        };
+
+        // The strategies only delete values which can't be part of a solution, so broken rules mean that it doesn't exist
+        let resultManager = resultPointer.read().unwrap();
+        if !checkSudokuCorrectness(&resultManager)? {
+            return Err(sudokuError::NoSolution);
+        }
+        let status = if checkSudokuIsComplete(&resultManager) {
+            solveStatus::Solved
+        } else {
+            solveStatus::Unsolved
+        };
+        return Ok(solveResult{sudokuVar: sudoku::newContainer(resultManager.GetSudoku()?), status, deductions});
+    }
+}
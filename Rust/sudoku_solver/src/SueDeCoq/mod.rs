@@ -0,0 +1,173 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, sudokuUnit, unitsOfKind, cellName};
+use super::Deductions::deduction;
+use super::Subsets::getCombinations;
+use super::StrategyRunners::repeatUntilStable;
+
+// Find all the Sue de Coq of the sudoku, until there are no more values to delete
+pub fn findSueDeCoq(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, findGridSueDeCoq);
+}
+
+// Find the Sue de Coq: some cells of the intersection of a box and a line, with at least two values more than the
+// cells, are joined with cells of the rest of the line and cells of the rest of the box whose values are disjoint.
+// If the joined cells have as many values as cells, every value is placed once in them: the values of the line cells
+// and the ones not in the box cells are in the line, so they can be deleted from the other cells of the line, and in
+// the same way for the box. Updates signals if there are deleted values
+pub fn findGridSueDeCoq(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    let unsolved = |cells: Vec<(usize, usize)>| cells.into_iter().filter(|&(i, j)| sudokuCopy[i][j].len() > 1).collect::<Vec<(usize, usize)>>();
+    for boxUnit in unitsOfKind(unitKind::Box) {
+        for lineUnit in unitsOfKind(unitKind::Row).into_iter().chain(unitsOfKind(unitKind::Column)) {
+            let intersectionCells = unsolved(boxUnit.intersection(&lineUnit));
+            if intersectionCells.len() < 2 {
+                continue;
+            }
+            let lineCells = unsolved(lineUnit.cells().iter().filter(|&&(i, j)| !boxUnit.contains(i, j)).cloned().collect());
+            let boxCells = unsolved(boxUnit.cells().iter().filter(|&&(i, j)| !lineUnit.contains(i, j)).cloned().collect());
+            for size in 2..=intersectionCells.len() {
+                for combination in getCombinations((0..intersectionCells.len() as i8).collect(), size as i8, 0) {
+                    let cells: Vec<(usize, usize)> = combination.iter().map(|&position| intersectionCells[position as usize]).collect();
+                    let values = cellsValues(&sudokuCopy, &cells);
+                    if values.len() < size + 2 {
+                        continue;
+                    }
+                    for deductionVar in sueDeCoqDeductions(&sudokuCopy, (&boxUnit, &boxCells), (&lineUnit, &lineCells), &cells, values) {
+                        ioManager.read().unwrap().ApplyDeduction(deductionVar)?;
+                        updates = true;
+                    }
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Return the deductions of the Sue de Coq with the given cells of the intersection, joined with every group of line
+// cells and of box cells (without a definitive value) whose values are disjoint
+fn sueDeCoqDeductions(sudokuCopy: &[[candidateSet; 9]; 9], boxPart: (&sudokuUnit, &[(usize, usize)]), linePart: (&sudokuUnit, &[(usize, usize)]),
+                      cells: &[(usize, usize)], values: candidateSet) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    let (boxUnit, boxCells) = boxPart;
+    let (lineUnit, lineCells) = linePart;
+    for lineSubset in cellsSubsets(lineCells) {
+        let lineValues = cellsValues(sudokuCopy, &lineSubset);
+        // Every cell of the line must share a value with the intersection, otherwise it would be a separate naked subset
+        if lineSubset.iter().any(|&(i, j)| sudokuCopy[i][j].intersection(values).is_empty()) {
+            continue;
+        }
+        for boxSubset in cellsSubsets(boxCells) {
+            let boxValues = cellsValues(sudokuCopy, &boxSubset);
+            if !boxValues.intersection(lineValues).is_empty() || boxSubset.iter().any(|&(i, j)| sudokuCopy[i][j].intersection(values).is_empty()) ||
+                values.union(lineValues).union(boxValues).len() != cells.len() + lineSubset.len() + boxSubset.len() {
+                continue;
+            }
+            let names = |group: &[(usize, usize)]| group.iter().map(|&(i, j)| cellName(i, j)).collect::<Vec<String>>().join(" ");
+            let description = format!("{} {} in {} and {}, {} {} in {}, {} {} in {}", names(cells), values, boxUnit, lineUnit,
+                                      names(&lineSubset), lineValues, lineUnit, names(&boxSubset), boxValues, boxUnit);
+            let mut sueDeCoq = deduction::new("Sue de Coq", description);
+            let lineDeleted = lineValues.union(values.difference(boxValues));
+            let boxDeleted = boxValues.union(values.difference(lineValues));
+            for &(i, j) in lineCells.iter().filter(|cell| !lineSubset.contains(cell)) {
+                for value in sudokuCopy[i][j].intersection(lineDeleted) {
+                    sueDeCoq.addElimination(i, j, value);
+                }
+            }
+            for &(i, j) in boxCells.iter().filter(|cell| !boxSubset.contains(cell)) {
+                for value in sudokuCopy[i][j].intersection(boxDeleted) {
+                    sueDeCoq.addElimination(i, j, value);
+                }
+            }
+            // The cells of the intersection not used by the pattern belong both to the line and to the box
+            for &(i, j) in boxUnit.intersection(lineUnit).iter().filter(|cell| !cells.contains(cell) && sudokuCopy[cell.0][cell.1].len() > 1) {
+                for value in sudokuCopy[i][j].intersection(lineDeleted.union(boxDeleted)) {
+                    sueDeCoq.addElimination(i, j, value);
+                }
+            }
+            if !sueDeCoq.eliminations.is_empty() {
+                deductions.push(sueDeCoq);
+            }
+        }
+    }
+    return deductions;
+}
+
+// Return every group of the given cells with at least one cell
+fn cellsSubsets(cells: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+    let mut subsets = Vec::new();
+    for size in 1..=cells.len() {
+        for combination in getCombinations((0..cells.len() as i8).collect(), size as i8, 0) {
+            subsets.push(combination.iter().map(|&position| cells[position as usize]).collect());
+        }
+    }
+    return subsets;
+}
+
+// Return the union of the values of the given cells
+fn cellsValues(sudokuCopy: &[[candidateSet; 9]; 9], cells: &[(usize, usize)]) -> candidateSet {
+    return cells.iter().fold(candidateSet::new(), |values, &(i, j)| values.union(sudokuCopy[i][j]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, managerOf, eliminationsOf, descriptionsOf};
+
+    // Cells of the row 1 outside the box 1, except r1c6, and cells of the box 1 outside the row 1, except r2c2
+    const LINE_CELLS: [(usize, usize); 5] = [(0, 3), (0, 4), (0, 6), (0, 7), (0, 8)];
+    const BOX_CELLS: [(usize, usize); 5] = [(1, 0), (1, 2), (2, 0), (2, 1), (2, 2)];
+
+    // Return the deductions of the Sue de Coq of the given sudoku, in which r1c6 has only 1 and 2, and r2c2 only 3 and 4
+    fn sueDeCoqOf(mut grid: [[candidateSet; 9]; 9]) -> Vec<deduction> {
+        setValues(&mut grid, &[1, 2], &[(0, 5)]);
+        setValues(&mut grid, &[3, 4], &[(1, 1)]);
+        let manager = managerOf(grid);
+        findGridSueDeCoq(manager.clone()).unwrap();
+        return manager.read().unwrap().GetDeductions();
+    }
+
+    // Return the deletion of the given values from the given cells, sorted
+    fn deletions(cells: &[(usize, usize)], values: &[i8]) -> Vec<(usize, usize, i8)> {
+        let mut eliminations: Vec<(usize, usize, i8)> = cells.iter().flat_map(|&(i, j)| values.iter().map(move |&value| (i, j, value))).collect();
+        eliminations.sort_unstable();
+        return eliminations;
+    }
+
+    #[test]
+    fn sueDeCoqWithTwoCells() {
+        // r1c1 r1c2 have the values 1 2 3 4: 1 and 2 must be in the row 1 with r1c6, 3 and 4 in the box 1 with r2c2
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2, 3], &[(0, 0)]);
+        setValues(&mut grid, &[1, 2, 4], &[(0, 1)]);
+        let deductions = sueDeCoqOf(grid);
+        assert_eq!(descriptionsOf(&deductions), vec!["Sue de Coq: r1c1 r1c2 {1,2,3,4} in box 1 and row 1, r1c6 {1,2} in row 1, r2c2 {3,4} in box 1"]);
+        // r1c3 is both in the row and in the box
+        let mut expected = deletions(&LINE_CELLS, &[1, 2]);
+        expected.extend(deletions(&BOX_CELLS, &[3, 4]));
+        expected.extend(deletions(&[(0, 2)], &[1, 2, 3, 4]));
+        expected.sort_unstable();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn sueDeCoqWithExtraValue() {
+        // r1c1 r1c2 r1c3 have the values 1 2 3 4 5, and 5 isn't in r1c6 or r2c2: so it's in the intersection, and it can
+        // be deleted both from the row and from the box
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 3, 5], &[(0, 0)]);
+        setValues(&mut grid, &[2, 4, 5], &[(0, 1)]);
+        setValues(&mut grid, &[1, 2, 3, 4], &[(0, 2)]);
+        let deductions = sueDeCoqOf(grid);
+        assert_eq!(descriptionsOf(&deductions), vec!["Sue de Coq: r1c1 r1c2 r1c3 {1,2,3,4,5} in box 1 and row 1, r1c6 {1,2} in row 1, r2c2 {3,4} in box 1"]);
+        let mut expected = deletions(&LINE_CELLS, &[1, 2, 5]);
+        expected.extend(deletions(&BOX_CELLS, &[3, 4, 5]));
+        expected.sort_unstable();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Deductions::{deduction, applyNewDeductions};
+use super::StrategyRunners::repeatUntilStable;
+
+// Maximum number of pairs of templates compared when two values are combined, bigger lists are skipped
+pub const MAX_TEMPLATE_PAIRS: usize = 2_000_000;
+
+// Find all the eliminations of the templates of every value, and if required of the combinations of the templates of
+// two values, until there are no more values to delete
+pub fn findTemplates(ioManager: Arc<RwLock<sudokuIOManager>>, combinePairs: bool) -> Result<(), sudokuError> {
+    // The templates don't depend on the sudoku, so they are generated once
+    let templates = allTemplates();
+    return repeatUntilStable(ioManager, |manager| findGridTemplates(manager, &templates, combinePairs));
+}
+
+// Return the 46656 templates: the ways to place a value in the sudoku, once in every row, column and box. Every
+// template is the mask of its cells, in which the cell (i, j) is the bit i*9+j
+pub fn allTemplates() -> Vec<u128> {
+    let mut templates = Vec::with_capacity(46656);
+    addTemplates(0, 0, 0, 0, &mut templates);
+    return templates;
+}
+
+// Add the templates that complete the given one, which has a cell in every row before the given row. The columns
+// and the boxes of the current band already used are saved as masks
+fn addTemplates(row: usize, template: u128, usedColumns: u16, usedBoxes: u8, templates: &mut Vec<u128>) {
+    if row == 9 {
+        templates.push(template);
+        return;
+    }
+    // The boxes are used again in every band
+    let bandBoxes = match row % 3 {
+        0 => 0,
+        _ => usedBoxes
+    };
+    for column in 0..9 {
+        if usedColumns & (1 << column) != 0 || bandBoxes & (1 << (column / 3)) != 0 {
+            continue;
+        }
+        addTemplates(row + 1, template | 1 << (row*9 + column), usedColumns | 1 << column, bandBoxes | 1 << (column / 3), templates);
+    }
+}
+
+// Keep the templates of every value compatible with the sudoku and delete the values in no template, or the other
+// values of the cells in every template. Updates signals if there are deleted values
+pub fn findGridTemplates(ioManager: Arc<RwLock<sudokuIOManager>>, templates: &[u128], combinePairs: bool) -> Result<bool, sudokuError> {
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    let mut deductions = Vec::new();
+    // The valid templates of the value n are saved in the position n
+    let mut valueTemplates: Vec<Vec<u128>> = vec![Vec::new(); 10];
+    for value in candidateSet::all() {
+        valueTemplates[value as usize] = validTemplates(&sudokuCopy, templates, value);
+        let description = format!("value {}, {} valid templates", value, valueTemplates[value as usize].len());
+        deductions.extend(templatesDeduction(&sudokuCopy, value, &valueTemplates[value as usize], "Templates", description));
+    }
+    if combinePairs {
+        // A template of a value is valid only if a template of every other value doesn't have cells in common with it
+        for value in candidateSet::all() {
+            for other in candidateSet::all().difference(candidateSet::single(value)) {
+                let (current, otherTemplates) = (&valueTemplates[value as usize], &valueTemplates[other as usize]);
+                if current.len() * otherTemplates.len() > MAX_TEMPLATE_PAIRS {
+                    continue;
+                }
+                let compatible: Vec<u128> = current.iter().filter(|&&template| otherTemplates.iter().any(|&otherTemplate| template & otherTemplate == 0)).cloned().collect();
+                if compatible.len() == current.len() {
+                    continue;
+                }
+                let description = format!("value {}, {} templates compatible with the templates of value {}", value, compatible.len(), other);
+                deductions.extend(templatesDeduction(&sudokuCopy, value, &compatible, "Template Pairs", description));
+                valueTemplates[value as usize] = compatible;
+            }
+        }
+    }
+    // The combinations repeat the deletions of the single values, so only the new deletions are applied
+    return applyNewDeductions(&ioManager.read().unwrap(), deductions);
+}
+
+// Return the templates of the value compatible with the sudoku: every cell of the template can have the value, and
+// every cell in which the value is definitive belongs to the template
+pub fn validTemplates(sudokuCopy: &[[candidateSet; 9]; 9], templates: &[u128], value: i8) -> Vec<u128> {
+    let mut possibleCells = 0u128;
+    let mut definitiveCells = 0u128;
+    for i in 0..9 {
+        for j in 0..9 {
+            if sudokuCopy[i][j].contains(value) {
+                possibleCells |= 1 << (i*9 + j);
+                if sudokuCopy[i][j].len() == 1 {
+                    definitiveCells |= 1 << (i*9 + j);
+                }
+            }
+        }
+    }
+    return templates.iter().filter(|&&template| template & !possibleCells == 0 && template & definitiveCells == definitiveCells).cloned().collect();
+}
+
+// Return the deduction of the given templates of the value: the value can be deleted from the cells without a template,
+// and the cells in every template must have the value
+fn templatesDeduction(sudokuCopy: &[[candidateSet; 9]; 9], value: i8, templates: &[u128], technique: &str, description: String) -> Option<deduction> {
+    // Without templates the sudoku has no solution, which will be found by the checks of the solver
+    if templates.is_empty() {
+        return None;
+    }
+    let unionCells = templates.iter().fold(0u128, |cells, &template| cells | template);
+    let commonCells = templates.iter().fold(!0u128, |cells, &template| cells & template);
+    let mut templatesVar = deduction::new(technique, description);
+    for i in 0..9 {
+        for j in 0..9 {
+            if sudokuCopy[i][j].len() < 2 {
+                continue;
+            }
+            if unionCells & (1 << (i*9 + j)) == 0 && sudokuCopy[i][j].contains(value) {
+                templatesVar.addElimination(i, j, value);
+            }
+            if commonCells & (1 << (i*9 + j)) != 0 {
+                for other in sudokuCopy[i][j].difference(candidateSet::single(value)) {
+                    templatesVar.addElimination(i, j, other);
+                }
+            }
+        }
+    }
+    if templatesVar.eliminations.is_empty() {
+        return None;
+    }
+    return Some(templatesVar);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, keepValue, removeValue, managerOf, eliminationsOf, techniquesOf, descriptionsOf};
+
+    // Return the deductions of the templates of the given sudoku
+    fn templatesOf(grid: [[candidateSet; 9]; 9], combinePairs: bool) -> Vec<deduction> {
+        let manager = managerOf(grid);
+        findGridTemplates(manager.clone(), &allTemplates(), combinePairs).unwrap();
+        return manager.read().unwrap().GetDeductions();
+    }
+
+    #[test]
+    fn templatesHaveACellInEveryUnit() {
+        let templates = allTemplates();
+        assert_eq!(templates.len(), 46656);
+        for &template in templates.iter() {
+            let cells: Vec<(usize, usize)> = (0..81).filter(|&bit| template & (1 << bit) != 0).map(|bit| (bit / 9, bit % 9)).collect();
+            assert_eq!(cells.len(), 9);
+            for index in 0..9 {
+                assert_eq!(cells.iter().filter(|&&(i, _j)| i == index).count(), 1);
+                assert_eq!(cells.iter().filter(|&&(_i, j)| j == index).count(), 1);
+                assert_eq!(cells.iter().filter(|&&(i, j)| (i / 3) * 3 + j / 3 == index).count(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn templatesOfSingleValue() {
+        // In the column 9 the value 4 is possible only in the box 9, which is a third of the templates, so the rest of
+        // the box can't be 4
+        let mut grid = fullGrid();
+        removeValue(&mut grid, 4, &(0..6).map(|i| (i, 8)).collect::<Vec<_>>());
+        let deductions = templatesOf(grid, true);
+        assert_eq!(descriptionsOf(&deductions), vec!["Templates: value 4, 15552 valid templates"]);
+        let expected: Vec<(usize, usize, i8)> = (6..9).flat_map(|i| (6..8).map(move |j| (i, j, 4))).collect();
+        assert_eq!(eliminationsOf(&deductions), expected);
+    }
+
+    #[test]
+    fn templatePairs() {
+        // The value 2 is possible only in the cells of a template, and in the row 1 the value 1 only in r1c1 and r1c9.
+        // The templates of 2 place it in r1c9 and delete the other values of its cells, while the templates of 1 that
+        // don't have cells in common with it place 1 in r1c1
+        let twoCells = [(0, 8), (1, 2), (2, 5), (3, 7), (4, 1), (5, 4), (6, 6), (7, 0), (8, 3)];
+        let mut grid = fullGrid();
+        keepValue(&mut grid, 2, &twoCells);
+        removeValue(&mut grid, 1, &[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (0, 6), (0, 7)]);
+        let single = templatesOf(grid, false);
+        assert_eq!(descriptionsOf(&single), vec!["Templates: value 2, 1 valid templates"]);
+        let mut expected: Vec<(usize, usize, i8)> = twoCells.iter().flat_map(|&(i, j)| (1..10).filter(|&value| value != 2).map(move |value| (i, j, value))).collect();
+        expected.sort_unstable();
+        assert_eq!(eliminationsOf(&single), expected);
+
+        let deductions = templatesOf(grid, true);
+        assert_eq!(techniquesOf(&deductions), vec!["Templates", "Template Pairs"]);
+        // The deletions of 1 from r2c3 and r8c1 are already made by the templates of 2
+        let mut expected: Vec<(usize, usize, i8)> = (3..10).map(|value| (0, 0, value)).collect();
+        expected.extend([(1, 0), (1, 1), (2, 0), (2, 1), (2, 2), (3, 0), (4, 0), (5, 0), (6, 0), (8, 0)].iter().map(|&(i, j)| (i, j, 1)));
+        assert_eq!(eliminationsOf(&deductions[1..]), expected);
+    }
+}
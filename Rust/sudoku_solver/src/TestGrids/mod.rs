@@ -0,0 +1,69 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuManager::sudoku;
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::ConstraintsElimination::sudokuConstraintsElimination;
+use super::Deductions::deduction;
+
+// Utilities of the tests, used to build the sudokus analyzed by the strategies and to check their deductions
+
+// Return a sudoku in which every cell can have every value
+pub fn fullGrid() -> [[candidateSet; 9]; 9] {
+    return [[candidateSet::all(); 9]; 9];
+}
+
+// Return the sudoku described by the given string, with the constraints of its definitive values already deleted
+pub fn puzzleGrid(description: &str) -> [[candidateSet; 9]; 9] {
+    return sudokuConstraintsElimination(sudoku::fromString(description.to_string()).unwrap().sudokuMatrix).unwrap();
+}
+
+// Give the same values to the given cells
+pub fn setValues(grid: &mut [[candidateSet; 9]; 9], values: &[i8], cells: &[(usize, usize)]) {
+    for &(i, j) in cells.iter() {
+        grid[i][j] = candidateSet::fromValues(values);
+    }
+}
+
+// Delete the value from the given cells
+pub fn removeValue(grid: &mut [[candidateSet; 9]; 9], value: i8, cells: &[(usize, usize)]) {
+    for &(i, j) in cells.iter() {
+        grid[i][j].remove(value);
+    }
+}
+
+// Keep the value only in the given cells, deleting it from every other cell
+pub fn keepValue(grid: &mut [[candidateSet; 9]; 9], value: i8, cells: &[(usize, usize)]) {
+    for i in 0..9 {
+        for j in 0..9 {
+            if cells.contains(&(i, j)) {
+                grid[i][j].insert(value);
+            } else {
+                grid[i][j].remove(value);
+            }
+        }
+    }
+}
+
+// Return a sudokuIOManager that contains the given sudoku
+pub fn managerOf(grid: [[candidateSet; 9]; 9]) -> Arc<RwLock<sudokuIOManager>> {
+    return Arc::new(RwLock::new(sudokuIOManager::new(sudoku::newContainer(grid))));
+}
+
+// Return the values deleted by the given deductions as (row, column, value), sorted and without repetitions
+pub fn eliminationsOf(deductions: &[deduction]) -> Vec<(usize, usize, i8)> {
+    let mut eliminations: Vec<(usize, usize, i8)> = deductions.iter()
+        .flat_map(|deductionVar| deductionVar.eliminations.iter().map(|deleted| (deleted.row, deleted.column, deleted.value))).collect();
+    eliminations.sort_unstable();
+    eliminations.dedup();
+    return eliminations;
+}
+
+// Return the techniques of the given deductions, in the same order
+pub fn techniquesOf(deductions: &[deduction]) -> Vec<String> {
+    return deductions.iter().map(|deductionVar| deductionVar.technique.clone()).collect();
+}
+
+// Return the techniques and the descriptions of the given deductions, in the same order
+pub fn descriptionsOf(deductions: &[deduction]) -> Vec<String> {
+    return deductions.iter().map(|deductionVar| format!("{}: {}", deductionVar.technique, deductionVar.description)).collect();
+}
@@ -0,0 +1,309 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::CandidateSet::candidateSet;
+use super::SudokuError::sudokuError;
+use super::Units::{unitKind, sudokuUnit, allUnits, cellName};
+use super::Deductions::deduction;
+use super::Subsets::getCombinations;
+use super::StrategyRunners::repeatUntilStable;
+
+// The strategies of this module are valid only for the sudokus with a unique solution: they delete the values that
+// would allow two solutions, so they must not be used on sudokus with more solutions
+
+// Find all the Unique Rectangles of the sudoku, until there are no more values to delete
+pub fn findUniqueRectangles(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, findGridUniqueRectangles);
+}
+
+// Find the Bivalue Universal Grave with a single cell with three values, until there are no more values to delete
+pub fn findBUG(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, findGridBUG);
+}
+
+// Find the Unique Rectangles: four cells in two rows, two columns and two boxes that contain the same two values xy.
+// If the four cells had only these values the sudoku would have two solutions, exchanging x and y, so at least one
+// cell must have a different value. Updates signals if there are deleted values
+pub fn findGridUniqueRectangles(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for firstRow in 0..9 {
+        for secondRow in firstRow+1..9 {
+            for firstColumn in 0..9 {
+                for secondColumn in firstColumn+1..9 {
+                    // The cells must be in two boxes, so the rows or the columns (but not both) must be in the same boxes
+                    if (firstRow / 3 == secondRow / 3) == (firstColumn / 3 == secondColumn / 3) {
+                        continue;
+                    }
+                    let corners = [(firstRow, firstColumn), (firstRow, secondColumn), (secondRow, firstColumn), (secondRow, secondColumn)];
+                    if corners.iter().any(|&(i, j)| sudokuCopy[i][j].len() < 2) {
+                        continue;
+                    }
+                    let commonValues = corners.iter().fold(candidateSet::all(), |values, &(i, j)| values.intersection(sudokuCopy[i][j]));
+                    for pair in getCombinations(commonValues.values(), 2, 0) {
+                        for deductionVar in rectangleDeductions(&sudokuCopy, &corners, candidateSet::fromValues(&pair)) {
+                            ioManager.read().unwrap().ApplyDeduction(deductionVar)?;
+                            updates = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Return the deductions of the Unique Rectangle with the given corners and values, for every type of rectangle
+fn rectangleDeductions(sudokuCopy: &[[candidateSet; 9]; 9], corners: &[(usize, usize); 4], pair: candidateSet) -> Vec<deduction> {
+    let mut deductions = Vec::new();
+    let extraCells: Vec<(usize, usize)> = corners.iter().filter(|&&(i, j)| sudokuCopy[i][j] != pair).cloned().collect();
+    let extraValues = extraCells.iter().fold(candidateSet::new(), |values, &(i, j)| values.union(sudokuCopy[i][j].difference(pair)));
+    let cornerNames: Vec<String> = corners.iter().map(|&(i, j)| cellName(i, j)).collect();
+    let description = format!("values {}, cells {}", pair, cornerNames.join(" "));
+    let sameLine = extraCells.len() == 2 && (extraCells[0].0 == extraCells[1].0 || extraCells[0].1 == extraCells[1].1);
+
+    // Type 1: only a cell has other values, so it can't be x or y
+    if extraCells.len() == 1 {
+        let (i, j) = extraCells[0];
+        let mut rectangle = deduction::new("Unique Rectangle Type 1", description.clone());
+        for value in pair {
+            rectangle.addElimination(i, j, value);
+        }
+        deductions.push(rectangle);
+    }
+
+    // Type 2 and type 5: the cells with other values have only the same extra value z, so one of them must be z and the
+    // value can be deleted from the cells that see all of them. In the type 2 they are in the same row or column
+    if extraCells.len() > 1 && extraValues.len() == 1 {
+        let z = extraValues.first().unwrap();
+        let technique = if sameLine { "Unique Rectangle Type 2" } else { "Unique Rectangle Type 5" };
+        let mut rectangle = deduction::new(technique, format!("{}, extra value {}", description, z));
+        rectangle.addCommonPeersEliminations(sudokuCopy, z, &extraCells, &extraCells);
+        if !rectangle.eliminations.is_empty() {
+            deductions.push(rectangle);
+        }
+    }
+
+    if sameLine {
+        for unit in sharedUnits(extraCells[0], extraCells[1]) {
+            // Type 3: one of the two cells has an extra value, so they act as a single cell with the extra values, which
+            // can form a naked subset with other cells of a unit that contains both
+            let otherCells: Vec<(usize, usize)> = unit.cells().iter().filter(|&&(i, j)| !extraCells.contains(&(i, j)) && sudokuCopy[i][j].len() > 1).cloned().collect();
+            for size in 1..4 {
+                if otherCells.len() <= size {
+                    break;
+                }
+                for combination in getCombinations((0..otherCells.len() as i8).collect(), size as i8, 0) {
+                    let subsetCells: Vec<(usize, usize)> = combination.iter().map(|&position| otherCells[position as usize]).collect();
+                    let subsetValues = subsetCells.iter().fold(extraValues, |values, &(i, j)| values.union(sudokuCopy[i][j]));
+                    if subsetValues.len() != size + 1 {
+                        continue;
+                    }
+                    let subsetNames: Vec<String> = subsetCells.iter().map(|&(i, j)| cellName(i, j)).collect();
+                    let mut rectangle = deduction::new("Unique Rectangle Type 3", format!("{}, naked subset {} with {} in {}", description, subsetValues, subsetNames.join(" "), unit));
+                    for &(i, j) in otherCells.iter().filter(|cell| !subsetCells.contains(cell)) {
+                        for value in sudokuCopy[i][j].intersection(subsetValues) {
+                            rectangle.addElimination(i, j, value);
+                        }
+                    }
+                    if !rectangle.eliminations.is_empty() {
+                        deductions.push(rectangle);
+                    }
+                }
+            }
+
+            // Type 4: if x is possible only in the two cells of a unit, one of them is x, so y can't be in both of them
+            for x in pair {
+                if unit.cells().iter().any(|&(i, j)| !extraCells.contains(&(i, j)) && sudokuCopy[i][j].contains(x)) {
+                    continue;
+                }
+                let y = pair.difference(candidateSet::single(x)).first().unwrap();
+                let mut rectangle = deduction::new("Unique Rectangle Type 4", format!("{}, strong link of {} in {}", description, x, unit));
+                for &(i, j) in extraCells.iter() {
+                    rectangle.addElimination(i, j, y);
+                }
+                deductions.push(rectangle);
+            }
+        }
+    }
+
+    // Type 6: the cells with other values are diagonal, and x is possible only in the rectangle in both its rows and both
+    // its columns. Then x must be in two diagonal cells, and if it were in the cells with other values the other two
+    // would be y, so x can be deleted from the cells with other values
+    if extraCells.len() == 2 && !sameLine {
+        let rows = [corners[0].0, corners[3].0];
+        let columns = [corners[0].1, corners[3].1];
+        for x in pair {
+            let outside = (0..9).any(|index| rows.iter().any(|&row| !columns.contains(&index) && sudokuCopy[row][index].contains(x)) ||
+                                            columns.iter().any(|&column| !rows.contains(&index) && sudokuCopy[index][column].contains(x)));
+            if outside {
+                continue;
+            }
+            let mut rectangle = deduction::new("Unique Rectangle Type 6", format!("{}, strong links of {} in the rows and columns", description, x));
+            for &(i, j) in extraCells.iter() {
+                rectangle.addElimination(i, j, x);
+            }
+            deductions.push(rectangle);
+        }
+    }
+    return deductions;
+}
+
+// Return the units (row, column or box) that contain both the given cells
+fn sharedUnits(first: (usize, usize), second: (usize, usize)) -> Vec<sudokuUnit> {
+    return unitKind::all().iter().map(|&kind| sudokuUnit::ofCell(kind, first.0, first.1)).filter(|unit| unit.contains(second.0, second.1)).collect();
+}
+
+// Find the Bivalue Universal Grave plus one: every cell without a definitive value has two values, except a cell with
+// three values. If the sudoku without the value that appears three times in the units of that cell had every value
+// twice in every unit, it would have two solutions, so the cell must have that value. Updates signals if there are deleted values
+pub fn findGridBUG(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let readManager = ioManager.read().unwrap();
+    let mut sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    let mut extraCell = None;
+    for i in 0..9 {
+        for j in 0..9 {
+            match sudokuCopy[i][j].len() {
+                1 | 2 => {}
+                3 if extraCell.is_none() => extraCell = Some((i, j)),
+                _ => return Ok(false)
+            }
+        }
+    }
+    let (i, j) = match extraCell {
+        Some(cell) => cell,
+        None => return Ok(false)
+    };
+    let cellValues = sudokuCopy[i][j];
+    for value in cellValues {
+        // Without the value every unit must have the other values in exactly two cells without a definitive value, or in
+        // a definitive cell
+        sudokuCopy[i][j] = cellValues.difference(candidateSet::single(value));
+        let grave = allUnits().iter().all(|unit| candidateSet::all().iter().all(|other| {
+            let cells: Vec<(usize, usize)> = unit.cells().iter().filter(|&&(ci, cj)| sudokuCopy[ci][cj].contains(other)).cloned().collect();
+            let solved = cells.iter().filter(|&&(ci, cj)| sudokuCopy[ci][cj].len() == 1).count();
+            return (cells.len() == 2 && solved == 0) || (cells.len() == 1 && solved == 1);
+        }));
+        if grave {
+            let mut bug = deduction::new("BUG+1", format!("cell {} must be {}", cellName(i, j), value));
+            for other in sudokuCopy[i][j] {
+                bug.addElimination(i, j, other);
+            }
+            ioManager.read().unwrap().ApplyDeduction(bug)?;
+            return Ok(true);
+        }
+    }
+    return Ok(false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, puzzleGrid, setValues, removeValue, managerOf, eliminationsOf, techniquesOf};
+
+    // Corners of the rectangle r1c1 r1c4 r2c1 r2c4 used by the tests, which is in the boxes 1 and 2
+    const CORNERS: [(usize, usize); 4] = [(0, 0), (0, 3), (1, 0), (1, 3)];
+    // Solution of the sudoku of the Wikipedia page about sudokus
+    const SOLUTION: &str = "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+
+    // Return the techniques and the eliminations of the deductions of the rectangle of the tests with the values 1 and 2
+    fn rectangleOf(grid: &[[candidateSet; 9]; 9]) -> (Vec<String>, Vec<(usize, usize, i8)>) {
+        let deductions = rectangleDeductions(grid, &CORNERS, candidateSet::fromValues(&[1, 2]));
+        return (techniquesOf(&deductions), eliminationsOf(&deductions));
+    }
+
+    #[test]
+    fn uniqueRectangleType1() {
+        // Only r2c4 has another value, so it can't be 1 or 2
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &CORNERS[..3]);
+        setValues(&mut grid, &[1, 2, 5], &[(1, 3)]);
+        assert_eq!(rectangleOf(&grid), (vec!["Unique Rectangle Type 1".to_string()], vec![(1, 3, 1), (1, 3, 2)]));
+    }
+
+    #[test]
+    fn uniqueRectangleType2() {
+        // r2c1 and r2c4 have also 5, so one of them is 5 and the rest of the row 2 can't be 5
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &CORNERS[..2]);
+        setValues(&mut grid, &[1, 2, 5], &CORNERS[2..]);
+        let expected: Vec<(usize, usize, i8)> = [1, 2, 4, 5, 6, 7, 8].iter().map(|&j| (1, j, 5)).collect();
+        assert_eq!(rectangleOf(&grid), (vec!["Unique Rectangle Type 2".to_string()], expected));
+    }
+
+    #[test]
+    fn uniqueRectangleType3() {
+        // r2c1 and r2c4 have also 5 and 6, so they form a naked pair with r2c9
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &CORNERS[..2]);
+        setValues(&mut grid, &[1, 2, 5], &[(1, 0)]);
+        setValues(&mut grid, &[1, 2, 6], &[(1, 3)]);
+        setValues(&mut grid, &[5, 6], &[(1, 8)]);
+        let expected: Vec<(usize, usize, i8)> = [1, 2, 4, 5, 6, 7].iter().flat_map(|&j| vec![(1, j, 5), (1, j, 6)]).collect();
+        assert_eq!(rectangleOf(&grid), (vec!["Unique Rectangle Type 3".to_string()], expected));
+    }
+
+    #[test]
+    fn uniqueRectangleType4() {
+        // In the row 2 the value 1 is possible only in r2c1 and r2c4, so they can't be 2
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &CORNERS[..2]);
+        setValues(&mut grid, &[1, 2, 5, 6], &CORNERS[2..]);
+        removeValue(&mut grid, 1, &[(1, 1), (1, 2), (1, 4), (1, 5), (1, 6), (1, 7), (1, 8)]);
+        assert_eq!(rectangleOf(&grid), (vec!["Unique Rectangle Type 4".to_string()], vec![(1, 0, 2), (1, 3, 2)]));
+    }
+
+    #[test]
+    fn uniqueRectangleType5() {
+        // The diagonal cells r1c4 and r2c1 have also 5, so the cells that see both can't be 5
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0), (1, 3)]);
+        setValues(&mut grid, &[1, 2, 5], &[(0, 3), (1, 0)]);
+        assert_eq!(rectangleOf(&grid), (vec!["Unique Rectangle Type 5".to_string()], vec![(0, 1, 5), (0, 2, 5), (1, 4, 5), (1, 5, 5)]));
+    }
+
+    #[test]
+    fn uniqueRectangleType6() {
+        // In the rows 1 and 2 and in the columns 1 and 4 the value 1 is possible only in the rectangle, so it must be in
+        // r1c1 and r2c4, otherwise they would be 2
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0), (1, 3)]);
+        setValues(&mut grid, &[1, 2, 5], &[(0, 3)]);
+        setValues(&mut grid, &[1, 2, 6], &[(1, 0)]);
+        let lines: Vec<(usize, usize)> = (0..9).flat_map(|index| vec![(0, index), (1, index), (index, 0), (index, 3)]).collect();
+        let outside: Vec<(usize, usize)> = lines.into_iter().filter(|cell| !CORNERS.contains(cell)).collect();
+        removeValue(&mut grid, 1, &outside);
+        assert_eq!(rectangleOf(&grid), (vec!["Unique Rectangle Type 6".to_string()], vec![(0, 3, 1), (1, 0, 1)]));
+    }
+
+    #[test]
+    fn uniqueRectangleWithoutExtraValues() {
+        // Without other values the rectangle is already a deadly pattern, so nothing can be deleted
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &CORNERS);
+        assert_eq!(rectangleOf(&grid), (vec![], vec![]));
+    }
+
+    #[test]
+    fn bugPlusOne() {
+        // In the rows 2, 4 and 7 and in the columns 7, 8 and 9 every cell has two values, except r4c7 which has three.
+        // Without 4 in r4c7 every value would be twice in every unit, so r4c7 must be 4, like in the solution
+        let mut grid = puzzleGrid(SOLUTION);
+        setValues(&mut grid, &[3, 4], &[(1, 6), (3, 8)]);
+        setValues(&mut grid, &[4, 8], &[(1, 7), (6, 8)]);
+        setValues(&mut grid, &[3, 8], &[(1, 8)]);
+        setValues(&mut grid, &[2, 4], &[(3, 7), (6, 6)]);
+        setValues(&mut grid, &[2, 8], &[(6, 7)]);
+        // Without a cell with three values there is no value to place
+        assert!(!findGridBUG(managerOf(grid)).unwrap());
+        setValues(&mut grid, &[2, 3, 4], &[(3, 6)]);
+        let manager = managerOf(grid);
+        assert!(findGridBUG(manager.clone()).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(techniquesOf(&deductions), vec!["BUG+1"]);
+        assert_eq!(deductions[0].description, "cell r4c7 must be 4");
+        assert_eq!(eliminationsOf(&deductions), vec![(3, 6, 2), (3, 6, 3)]);
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+
+// Kinds of units of the sudoku, the cells of every unit must have different values
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum unitKind {
+    Row, Column, Box
+}
+
+impl unitKind {
+    // Return every kind of unit
+    pub fn all() -> [unitKind; 3] {
+        return [unitKind::Row, unitKind::Column, unitKind::Box];
+    }
+}
+
+// Row, column or box of the sudoku, identified by its kind and its index from 0 to 8. The boxes are numbered from left
+// to right and from top to bottom
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct sudokuUnit {
+    pub kind: unitKind,
+    pub index: usize
+}
+
+impl sudokuUnit {
+    pub fn new(kind: unitKind, index: usize) -> sudokuUnit {
+        return sudokuUnit{kind, index};
+    }
+
+    // Return the unit of the given kind that contains the cell in the given position
+    pub fn ofCell(kind: unitKind, row: usize, column: usize) -> sudokuUnit {
+        match kind {
+            unitKind::Row => return sudokuUnit::new(kind, row),
+            unitKind::Column => return sudokuUnit::new(kind, column),
+            unitKind::Box => return sudokuUnit::new(kind, boxIndex(row, column))
+        }
+    }
+
+    // Return the position (row and column) of the cells of the unit. The cells of a box are ordered with the
+    // formula ib*3+jb, where ib and jb are the row and the column of the cell inside the box
+    pub fn cells(&self) -> [(usize, usize); 9] {
+        let mut cells = [(0, 0); 9];
+        for position in 0..9 {
+            cells[position] = match self.kind {
+                unitKind::Row => (self.index, position),
+                unitKind::Column => (position, self.index),
+                unitKind::Box => ((self.index/3)*3 + position/3, (self.index%3)*3 + position%3)
+            };
+        }
+        return cells;
+    }
+
+    // Check if the cell in the given position belongs to the unit
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        return sudokuUnit::ofCell(self.kind, row, column) == *self;
+    }
+
+    // Return the cells that belong both to this unit and to the given one, in the order of this unit
+    pub fn intersection(&self, other: &sudokuUnit) -> Vec<(usize, usize)> {
+        return self.cells().iter().filter(|&&(i, j)| other.contains(i, j)).cloned().collect();
+    }
+}
+
+// Return the index of the box that contains the cell in the given position
+pub fn boxIndex(row: usize, column: usize) -> usize {
+    return (row/3)*3 + column/3;
+}
+
+// Return the nine units of the given kind
+pub fn unitsOfKind(kind: unitKind) -> Vec<sudokuUnit> {
+    return (0..9).map(|index| sudokuUnit::new(kind, index)).collect();
+}
+
+// Return every unit of the sudoku, first the rows, then the columns and at the end the boxes
+pub fn allUnits() -> Vec<sudokuUnit> {
+    return unitKind::all().iter().flat_map(|&kind| unitsOfKind(kind)).collect();
+}
+
+// Check if two different cells see each other, that is they belong to the same row, column or box
+pub fn seesCell(first: (usize, usize), second: (usize, usize)) -> bool {
+    return first != second && (first.0 == second.0 || first.1 == second.1 || boxIndex(first.0, first.1) == boxIndex(second.0, second.1));
+}
+
+// Return the position of the 20 cells that see the cell in the given position
+pub fn peers(row: usize, column: usize) -> Vec<(usize, usize)> {
+    let mut peersList = Vec::new();
+    for i in 0..9 {
+        for j in 0..9 {
+            if seesCell((row, column), (i, j)) {
+                peersList.push((i, j));
+            }
+        }
+    }
+    return peersList;
+}
+
+// Return the name of the cell in the given position, with row and column starting from 1 (for example r1c5)
+pub fn cellName(row: usize, column: usize) -> String {
+    return format!("r{}c{}", row + 1, column + 1);
+}
+
+impl fmt::Display for unitKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            unitKind::Row => write!(f, "row"),
+            unitKind::Column => write!(f, "column"),
+            unitKind::Box => write!(f, "box")
+        }
+    }
+}
+
+impl fmt::Display for sudokuUnit {
+    // The index is printed starting from 1
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.index + 1)
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::{Arc, RwLock};
+use super::SudokuIOManager::sudokuIOManager;
+use super::SudokuError::sudokuError;
+use super::Units::{peers, cellName};
+use super::Deductions::deduction;
+use super::StrategyRunners::repeatUntilStable;
+
+// Find all the XY-Wings of the sudoku, until there are no more values to delete
+pub fn findXYWings(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, findGridXYWings);
+}
+
+// Find all the XYZ-Wings of the sudoku, until there are no more values to delete
+pub fn findXYZWings(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<(), sudokuError> {
+    return repeatUntilStable(ioManager, findGridXYZWings);
+}
+
+// Find the XY-Wings: a pivot with the values xy sees two pincers with the values xz and yz, so one of the pincers must
+// be z and the value z can be deleted from the cells that see both the pincers. Updates signals if there are deleted values
+pub fn findGridXYWings(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for i in 0..9 {
+        for j in 0..9 {
+            let pivotValues = sudokuCopy[i][j];
+            if pivotValues.len() != 2 {
+                continue;
+            }
+            // Save the cells that see the pivot with two values, one of which is shared with the pivot
+            let pincers: Vec<(usize, usize)> = peers(i, j).into_iter()
+                .filter(|&(pi, pj)| sudokuCopy[pi][pj].len() == 2 && sudokuCopy[pi][pj].intersection(pivotValues).len() == 1).collect();
+            for (position, &first) in pincers.iter().enumerate() {
+                for &second in pincers[position+1..].iter() {
+                    let firstValues = sudokuCopy[first.0][first.1];
+                    let secondValues = sudokuCopy[second.0][second.1];
+                    // The pincers must share a different value of the pivot and the same value z, which isn't in the pivot
+                    let zValues = firstValues.difference(pivotValues);
+                    if firstValues.intersection(pivotValues) == secondValues.intersection(pivotValues) || zValues != secondValues.difference(pivotValues) {
+                        continue;
+                    }
+                    let z = zValues.first().unwrap();
+                    let description = format!("pivot {} {}, pincers {} {}, {} {}", cellName(i, j), pivotValues,
+                                              cellName(first.0, first.1), firstValues, cellName(second.0, second.1), secondValues);
+                    let mut wing = deduction::new("XY-Wing", description);
+                    wing.addCommonPeersEliminations(&sudokuCopy, z, &[first, second], &[]);
+                    if !wing.eliminations.is_empty() {
+                        ioManager.read().unwrap().ApplyDeduction(wing)?;
+                        updates = true;
+                    }
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+// Find the XYZ-Wings: a pivot with the values xyz sees two pincers with the values xz and yz, so one of the three cells
+// must be z and the value z can be deleted from the cells that see all of them. Updates signals if there are deleted values
+pub fn findGridXYZWings(ioManager: Arc<RwLock<sudokuIOManager>>) -> Result<bool, sudokuError> {
+    let mut updates = false;
+    let readManager = ioManager.read().unwrap();
+    let sudokuCopy = readManager.GetSudoku()?;
+    drop(readManager);
+    for i in 0..9 {
+        for j in 0..9 {
+            let pivotValues = sudokuCopy[i][j];
+            if pivotValues.len() != 3 {
+                continue;
+            }
+            // Save the cells that see the pivot with two values, both contained in the pivot
+            let pincers: Vec<(usize, usize)> = peers(i, j).into_iter()
+                .filter(|&(pi, pj)| sudokuCopy[pi][pj].len() == 2 && sudokuCopy[pi][pj].isSubset(pivotValues)).collect();
+            for (position, &first) in pincers.iter().enumerate() {
+                for &second in pincers[position+1..].iter() {
+                    let firstValues = sudokuCopy[first.0][first.1];
+                    let secondValues = sudokuCopy[second.0][second.1];
+                    // The pincers must be different, so they share only the value z
+                    if firstValues == secondValues {
+                        continue;
+                    }
+                    let z = firstValues.intersection(secondValues).first().unwrap();
+                    let description = format!("pivot {} {}, pincers {} {}, {} {}", cellName(i, j), pivotValues,
+                                              cellName(first.0, first.1), firstValues, cellName(second.0, second.1), secondValues);
+                    let mut wing = deduction::new("XYZ-Wing", description);
+                    wing.addCommonPeersEliminations(&sudokuCopy, z, &[(i, j), first, second], &[]);
+                    if !wing.eliminations.is_empty() {
+                        ioManager.read().unwrap().ApplyDeduction(wing)?;
+                        updates = true;
+                    }
+                }
+            }
+        }
+    }
+    return Ok(updates);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestGrids::{fullGrid, setValues, managerOf, eliminationsOf, descriptionsOf};
+
+    #[test]
+    fn xyWing() {
+        // The pivot r1c1 {1,2} sees the pincers r1c5 {1,3} and r5c1 {2,3}, so one of them is 3 and r5c5 can't be 3
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(0, 4)]);
+        setValues(&mut grid, &[2, 3], &[(4, 0)]);
+        let manager = managerOf(grid);
+        assert!(!findGridXYZWings(manager.clone()).unwrap());
+        assert!(findGridXYWings(manager.clone()).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(descriptionsOf(&deductions), vec!["XY-Wing: pivot r1c1 {1,2}, pincers r1c5 {1,3}, r5c1 {2,3}"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(4, 4, 3)]);
+    }
+
+    #[test]
+    fn xyzWing() {
+        // The pivot r1c1 {1,2,3} sees the pincers r1c5 {1,3} and r2c2 {2,3}, so one of the three cells is 3 and the cells
+        // that see all of them can't be 3
+        let mut grid = fullGrid();
+        setValues(&mut grid, &[1, 2, 3], &[(0, 0)]);
+        setValues(&mut grid, &[1, 3], &[(0, 4)]);
+        setValues(&mut grid, &[2, 3], &[(1, 1)]);
+        let manager = managerOf(grid);
+        assert!(!findGridXYWings(manager.clone()).unwrap());
+        assert!(findGridXYZWings(manager.clone()).unwrap());
+        let deductions = manager.read().unwrap().GetDeductions();
+        assert_eq!(descriptionsOf(&deductions), vec!["XYZ-Wing: pivot r1c1 {1,2,3}, pincers r1c5 {1,3}, r2c2 {2,3}"]);
+        assert_eq!(eliminationsOf(&deductions), vec![(0, 1, 3), (0, 2, 3)]);
+    }
+}
@@ -0,0 +1,56 @@
+// The names follow the convention of the Go version of the solver
+#![allow(non_snake_case, non_camel_case_types)]
+#![allow(clippy::needless_return, clippy::needless_range_loop)]
+
+pub mod SudokuError;
+pub mod CandidateSet;
+pub mod SudokuManager;
+pub mod SudokuIOManager;
+pub mod CheckSudokuMethods;
+pub mod ConstraintsElimination;
+pub mod HiddenSingles;
+pub mod Units;
+pub mod Deductions;
+pub mod StrategyRunners;
+pub mod Subsets;
+pub mod LockedCandidates;
+pub mod Fish;
+pub mod Wings;
+pub mod StrongLinks;
+pub mod LinkPatterns;
+pub mod Coloring;
+pub mod Chains;
+pub mod Uniqueness;
+pub mod AlmostLockedSets;
+pub mod SueDeCoq;
+pub mod ForcingChains;
+pub mod Templates;
+pub mod BruteForceMethods;
+pub mod SudokuSolver;
+#[cfg(test)]
+mod TestGrids;
+
+pub use SudokuError::sudokuError;
+pub use CandidateSet::candidateSet;
+pub use SudokuManager::sudoku;
+pub use SudokuIOManager::sudokuIOManager;
+pub use SudokuSolver::{sudokuSolver, strategy, solveResult, solveStatus};
+pub use CheckSudokuMethods::{checkSudokuIsComplete, checkSudokuCorrectness, checkBaseSudokuCorrectness};
+pub use ConstraintsElimination::{constraintsElimination, sudokuConstraintsElimination};
+pub use HiddenSingles::findHiddenSingles;
+pub use Units::{unitKind, sudokuUnit};
+pub use Deductions::{deduction, elimination};
+pub use Subsets::{subsetKind, findSubsets, findNakedPairs, findNakedTriples, findNakedQuads, findHiddenPairs, findHiddenTriples, findHiddenQuads};
+pub use LockedCandidates::{findPointingCandidates, findClaimingCandidates};
+pub use Fish::{findFish, findXWings, findSwordfish, findJellyfish, findFinnedFish, findFinnedXWings, findFinnedSwordfish, findFinnedJellyfish};
+pub use Wings::{findXYWings, findXYZWings};
+pub use StrongLinks::{strongLink, strongLinkIndex};
+pub use LinkPatterns::{linkPattern, findLinkPatterns};
+pub use Coloring::{colorCluster, buildClusters, findColoring, findSimpleColoring, findMultiColoring, findMedusa};
+pub use Chains::{chainKind, chainNode, chainGraph, findChains, findXCycles, findAlternatingInferenceChains, DEFAULT_CHAIN_LENGTH};
+pub use Uniqueness::{findUniqueRectangles, findBUG};
+pub use AlmostLockedSets::{alsPattern, almostLockedSet, alsIndex, findAlmostLockedSets};
+pub use SueDeCoq::findSueDeCoq;
+pub use ForcingChains::{forcingKind, forcingBranch, findForcingChains, DEFAULT_FORCING_DEPTH};
+pub use Templates::{allTemplates, validTemplates, findTemplates};
+pub use BruteForceMethods::{bruteForceSolving, bruteForceResult, countSolutions, isUnique, findSolutions, solutionsIterator, countPossibilities};
@@ -1,118 +1,346 @@
-use std::sync::{Arc, RwLock};
-use std::time::SystemTime;
-mod SudokuIOManager;
-use SudokuIOManager::sudokuIOManager;
-mod SudokuManager;
-use SudokuManager::sudoku;
-mod CheckSudokuMethods;
-use CheckSudokuMethods::{checkSudokuIsComplete, checkSudokuCorrectness};
-mod BruteForceMethods;
-use BruteForceMethods::bruteForceSolving;
-mod ConstraintsElimination;
-use ConstraintsElimination::constraintsElimination;
-mod NakedPairs;
-use NakedPairs::findNakedPairs;
-mod NakedTriples;
-use NakedTriples::findNakedTriples;
+// The names follow the convention of the Go version of the solver
+#![allow(non_snake_case, non_camel_case_types)]
+#![allow(clippy::needless_return, clippy::needless_range_loop)]
+
+use std::env;
+use std::io::{self, Read};
+use std::process;
+use std::time::{Duration, SystemTime};
+use sudoku_solver::{sudoku, sudokuError, sudokuIOManager, sudokuSolver, strategy, solveStatus, checkSudokuCorrectness, countSolutions, findSolutions, countPossibilities, DEFAULT_CHAIN_LENGTH, DEFAULT_FORCING_DEPTH};
+
+// Exit codes of the program
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_USAGE: i32 = 1;
+const EXIT_INVALID: i32 = 2;
+const EXIT_UNSOLVED: i32 = 3;
+const EXIT_NO_SOLUTION: i32 = 4;
+const EXIT_MULTIPLE_SOLUTIONS: i32 = 5;
+
+const USAGE: &str = "Usage: sudoku_solver <command> [options] [file]
+
+Commands:
+  solve       Solve the sudoku and print the result
+  validate    Check that the definitive values of the sudoku respect the rules and that the solution is unique
+  print       Print the sudoku without solving it
+  solutions   Print every solution of the sudoku
+
+Options:
+  -s, --strategies <list>   Comma separated list of the strategies to run, chosen among
+                            constraints, hidden-singles, pointing, claiming, naked-pairs,
+                            naked-triples, hidden-pairs, hidden-triples, naked-quads, hidden-quads,
+                            x-wing, swordfish, jellyfish, finned-x-wing, finned-swordfish,
+                            finned-jellyfish, xy-wing, xyz-wing, skyscraper, two-string-kite,
+                            empty-rectangle, w-wing, simple-coloring, multi-coloring, medusa,
+                            x-cycle, aic, als-xz, als-xy-wing, death-blossom, sue-de-coq,
+                            unique-rectangle, bug, templates, template-pairs, cell-forcing-chains,
+                            unit-forcing-chains, contradiction-chains and brute-force (default: all)
+  -u, --unique              Solve the sudoku only if it has a unique solution
+  -a, --assume-unique       Use the strategies valid only for sudokus with a unique solution
+                            (unique-rectangle and bug)
+  -e, --explain             Print the deductions that explain the values deleted by the strategies
+  -c, --chain-length <links> Maximum number of links of the chains (default: 12)
+  -d, --forcing-depth <steps> Maximum number of propagation steps of the forcing chains (default: 8)
+  -n, --max <count>         Maximum number of solutions printed by the solutions command
+  -l, --time-limit <seconds> Stop the brute force search after the given number of seconds
+  -t, --time                Print the execution time in microseconds
+  -h, --help                Print this message
+
+If the file is omitted or is \"-\" the sudoku is read from the standard input.
+
+Exit codes:
+  0   the sudoku is solved (solve), valid with a unique solution (validate) or has at least a solution (solutions)
+  1   wrong arguments, unreadable input or internal error
+  2   the sudoku is malformed or its definitive values break the rules
+  3   the selected strategies could not solve the sudoku, or the time limit has been reached
+  4   the sudoku doesn't have a solution
+  5   the sudoku has more than one solution";
+
+// Commands accepted by the program
+#[derive(PartialEq, Clone, Copy)]
+enum command {
+    Solve, Validate, Print, Solutions
+}
+
+// Options obtained from the command line arguments
+struct arguments {
+    command: command,
+    path: Option<String>,
+    strategies: Vec<strategy>,
+    timeLimit: Option<Duration>,
+    chainLength: usize,
+    forcingDepth: usize,
+    unique: bool,
+    assumeUnique: bool,
+    explain: bool,
+    maxSolutions: Option<usize>,
+    time: bool
+}
 
 fn main() {
-    let testBruteForce = false;
     let start = SystemTime::now();
+    let args = match parseArguments(env::args().skip(1).collect()) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(EXIT_USAGE);
+        }
+    };
 
-    if testBruteForce {
-        // Load sudoku from file
-        let path = "../../Sudokus/Brute Force Test.txt";
-        let sudokuVar = sudoku::new(path.to_string());
-        sudokuVar.printSudoku();
-        println!("\n\n");
-
-        // Run IOManager
-        let ioManager = sudokuIOManager::new(sudokuVar);
-        let ioManagerPointer = Arc::new(RwLock::new(ioManager));
-        
-        // Use brute force to find a solution if the sudoku is not complete. Note that it should be a method to avoid, because the
-		// complexity is exponential and so a lot of constraints implies that a lot of time is required, although it uses parallelism to speed up
-        SudokuIOManager::Run(ioManagerPointer.clone());
-
-        {
-            let newManager = bruteForceSolving(ioManagerPointer.clone());
-
-            let newManagerPointer = Arc::new(RwLock::new(newManager));
-            // Run the manager to delete the values sent in the specific channel
-            SudokuIOManager::Run(newManagerPointer.clone());
-
-            {
-                let newManager = newManagerPointer.read().unwrap();
-                newManager.PrintSudoku();
-                if checkSudokuIsComplete(&newManager) {
-                    println!("Sudoku is complete");
-                }
-                if checkSudokuCorrectness(&newManager) {
-                    println!("The actual solution of the sudoku is correct");
-                }
+    let exitCode = match run(&args) {
+        Ok(exitCode) => exitCode,
+        Err(error) => {
+            eprintln!("{}", error);
+            errorExitCode(&error)
+        }
+    };
+
+    if args.time {
+        println!("Main time: {}", (SystemTime::now().duration_since(start).expect("Time")).as_micros());
+    }
+    process::exit(exitCode);
+}
+
+// Execute the command given in input and return the exit code
+fn run(args: &arguments) -> Result<i32, sudokuError> {
+    // Load sudoku from file or from the standard input
+    let sudokuVar = loadSudoku(&args.path)?;
+
+    match args.command {
+        command::Print => {
+            sudokuVar.printSudoku();
+            println!("Possible combinations: {}", countPossibilities(sudokuVar.sudokuMatrix));
+            return Ok(EXIT_SUCCESS);
+        }
+        command::Validate => {
+            let ioManager = sudokuIOManager::new(sudokuVar);
+            if !checkSudokuCorrectness(&ioManager)? {
+                return Err(sudokuError::ContradictoryGivens);
+            }
+            let exitCode = checkUniqueness(&sudokuVar, args)?;
+            if exitCode == EXIT_SUCCESS {
+                println!("The sudoku is valid and has a unique solution");
+            }
+            return Ok(exitCode);
+        }
+        command::Solve => return solve(sudokuVar, args),
+        command::Solutions => {
+            let mut solutionsNumber = 0;
+            for solution in findSolutions(sudokuVar.sudokuMatrix, args.maxSolutions)? {
+                solutionsNumber += 1;
+                println!("Solution {}:", solutionsNumber);
+                solution?.printSudoku();
+                println!("\n");
             }
+            if solutionsNumber == 0 {
+                return Err(sudokuError::NoSolution);
+            }
+            return Ok(EXIT_SUCCESS);
+        }
+    }
+}
+
+// Run the selected strategies on the sudoku, print the result and return the exit code
+fn solve(sudokuVar: sudoku, args: &arguments) -> Result<i32, sudokuError> {
+    if args.unique {
+        let exitCode = checkUniqueness(&sudokuVar, args)?;
+        if exitCode != EXIT_SUCCESS {
+            return Ok(exitCode);
+        }
+    }
+    let mut solver = sudokuSolver::withStrategies(args.strategies.clone());
+    solver.timeLimit = args.timeLimit;
+    solver.chainLength = args.chainLength;
+    solver.forcingDepth = args.forcingDepth;
+    solver.assumeUniqueness = args.assumeUnique;
+    let result = solver.solve(sudokuVar)?;
+    if args.explain {
+        for deductionVar in result.deductions.iter() {
+            println!("{}", deductionVar);
         }
-    } else {
-        // Load sudoku from file
-        let path = "../../Sudokus/Gentle.txt";
-        let sudokuVar = sudoku::new(path.to_string());
-        sudokuVar.printSudoku();
-        println!("\n\n");
-
-        // Run IOManager
-        let ioManager = sudokuIOManager::new(sudokuVar);
-        let ioManagerPointer = Arc::new(RwLock::new(ioManager));
-
-        SudokuIOManager::Run(ioManagerPointer.clone());
-
-        // Delete the invalid constraints
-        constraintsElimination(ioManagerPointer.clone());
-        {
-            let ioManager = ioManagerPointer.read().unwrap();
-            ioManager.PrintSudoku();
-            println!{"\n\n"};
+    }
+    result.sudokuVar.printSudoku();
+    if result.status == solveStatus::Solved {
+        println!("Sudoku is complete");
+        return Ok(EXIT_SUCCESS);
+    }
+    println!("Sudoku is not complete");
+    return Ok(EXIT_UNSOLVED);
+}
+
+// Count the solutions of the sudoku (stopping at the second one) and return the corresponding exit code
+fn checkUniqueness(sudokuVar: &sudoku, args: &arguments) -> Result<i32, sudokuError> {
+    match countSolutions(sudokuVar.sudokuMatrix, 2, args.timeLimit)? {
+        0 => return Err(sudokuError::NoSolution),
+        1 => return Ok(EXIT_SUCCESS),
+        _ => {
+            println!("The sudoku has more than one solution");
+            return Ok(EXIT_MULTIPLE_SOLUTIONS);
         }
+    }
+}
+
+// Return the exit code corresponding to the given error
+fn errorExitCode(error: &sudokuError) -> i32 {
+    match error {
+        sudokuError::MalformedGrid{..} | sudokuError::WrongCellCount(_) | sudokuError::ContradictoryGivens => EXIT_INVALID,
+        sudokuError::NoSolution => EXIT_NO_SOLUTION,
+        sudokuError::Timeout => EXIT_UNSOLVED,
+        sudokuError::Io(_) | sudokuError::ChannelClosed | sudokuError::ThreadPanicked => EXIT_USAGE
+    }
+}
 
-        findNakedPairs(ioManagerPointer.clone());
-        {
-            let ioManager = ioManagerPointer.read().unwrap();
-            ioManager.PrintSudoku();
-            println!{"\n\n"};
+// Read the sudoku from the file in the given path, or from the standard input if the path is missing
+fn loadSudoku(path: &Option<String>) -> Result<sudoku, sudokuError> {
+    match path {
+        Some(path) if path != "-" => return sudoku::new(path.to_string()),
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return sudoku::fromString(input);
         }
+    }
+}
 
-        findNakedTriples(ioManagerPointer.clone());
-        {
-            let ioManager = ioManagerPointer.read().unwrap();
-            ioManager.PrintSudoku();
-            println!{"\n\n"};
+// Convert the command line arguments into the program options
+fn parseArguments(args: Vec<String>) -> Result<arguments, String> {
+    if args.is_empty() {
+        return Err("Missing command".to_string());
+    }
+    let command = match args[0].as_str() {
+        "solve" => command::Solve,
+        "validate" => command::Validate,
+        "print" => command::Print,
+        "solutions" => command::Solutions,
+        "-h" | "--help" => {
+            println!("{}", USAGE);
+            process::exit(EXIT_SUCCESS);
         }
+        other => return Err(format!("Unknown command \"{}\"", other))
+    };
 
-        {
-            let ioManager = ioManagerPointer.read().unwrap();
-            if !checkSudokuIsComplete(&ioManager) {
-                // Delete the lock to allow the contraint elimination in bruteForceSolving function
-                drop(ioManager);
-                let newManager = bruteForceSolving(ioManagerPointer.clone());
-                let newManagerPointer = Arc::new(RwLock::new(newManager));
-                // Run the manager to delete the values sent in the specific channel
-                SudokuIOManager::Run(newManagerPointer.clone());
-
-                {
-                    let newManager = newManagerPointer.read().unwrap();
-                    if checkSudokuIsComplete(&newManager) {
-                        println!("Sudoku is complete");
-                    }
-                    if checkSudokuCorrectness(&newManager) {
-                        println!("The actual solution of the sudoku is correct");
-                    }
+    let mut result = arguments{command, path: None, strategies: strategy::all(), timeLimit: None, chainLength: DEFAULT_CHAIN_LENGTH, forcingDepth: DEFAULT_FORCING_DEPTH, unique: false, assumeUnique: false, explain: false, maxSolutions: None, time: false};
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-s" | "--strategies" => {
+                i += 1;
+                if i == args.len() {
+                    return Err(format!("Missing value of option \"{}\"", args[i-1]));
                 }
-            } else {
-                println!("Sudoku is complete");
-                if checkSudokuCorrectness(&ioManager) {
-                    println!("The actual solution of the sudoku is correct");
+                result.strategies = parseStrategies(&args[i])?;
+            }
+            "-l" | "--time-limit" => {
+                i += 1;
+                if i == args.len() {
+                    return Err(format!("Missing value of option \"{}\"", args[i-1]));
+                }
+                match args[i].parse::<f64>() {
+                    Ok(seconds) if seconds >= 0.0 => result.timeLimit = Some(Duration::from_secs_f64(seconds)),
+                    _ => return Err(format!("Invalid time limit \"{}\"", args[i]))
                 }
             }
+            "-c" | "--chain-length" => {
+                i += 1;
+                if i == args.len() {
+                    return Err(format!("Missing value of option \"{}\"", args[i-1]));
+                }
+                match args[i].parse::<usize>() {
+                    Ok(links) if links > 0 => result.chainLength = links,
+                    _ => return Err(format!("Invalid chain length \"{}\"", args[i]))
+                }
+            }
+            "-d" | "--forcing-depth" => {
+                i += 1;
+                if i == args.len() {
+                    return Err(format!("Missing value of option \"{}\"", args[i-1]));
+                }
+                match args[i].parse::<usize>() {
+                    Ok(steps) if steps > 0 => result.forcingDepth = steps,
+                    _ => return Err(format!("Invalid forcing depth \"{}\"", args[i]))
+                }
+            }
+            "-n" | "--max" => {
+                i += 1;
+                if i == args.len() {
+                    return Err(format!("Missing value of option \"{}\"", args[i-1]));
+                }
+                match args[i].parse::<usize>() {
+                    Ok(count) if count > 0 => result.maxSolutions = Some(count),
+                    _ => return Err(format!("Invalid number of solutions \"{}\"", args[i]))
+                }
+            }
+            "-u" | "--unique" => result.unique = true,
+            "-a" | "--assume-unique" => result.assumeUnique = true,
+            "-e" | "--explain" => result.explain = true,
+            "-t" | "--time" => result.time = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(EXIT_SUCCESS);
+            }
+            other => {
+                if other.starts_with('-') && other != "-" {
+                    return Err(format!("Unknown option \"{}\"", other));
+                }
+                if result.path.is_some() {
+                    return Err(format!("Unexpected argument \"{}\"", other));
+                }
+                result.path = Some(other.to_string());
+            }
+        }
+        i += 1;
+    }
+    return Ok(result);
+}
+
+// Convert a comma separated list of names into the corresponding strategies
+fn parseStrategies(list: &str) -> Result<Vec<strategy>, String> {
+    let mut strategies = Vec::new();
+    for name in list.split(',') {
+        let selected = match name.trim() {
+            "constraints" => strategy::ConstraintsElimination,
+            "hidden-singles" => strategy::HiddenSingles,
+            "pointing" => strategy::Pointing,
+            "claiming" => strategy::Claiming,
+            "naked-pairs" => strategy::NakedPairs,
+            "naked-triples" => strategy::NakedTriples,
+            "hidden-pairs" => strategy::HiddenPairs,
+            "hidden-triples" => strategy::HiddenTriples,
+            "naked-quads" => strategy::NakedQuads,
+            "hidden-quads" => strategy::HiddenQuads,
+            "x-wing" => strategy::XWing,
+            "swordfish" => strategy::Swordfish,
+            "jellyfish" => strategy::Jellyfish,
+            "finned-x-wing" => strategy::FinnedXWing,
+            "finned-swordfish" => strategy::FinnedSwordfish,
+            "finned-jellyfish" => strategy::FinnedJellyfish,
+            "xy-wing" => strategy::XYWing,
+            "xyz-wing" => strategy::XYZWing,
+            "skyscraper" => strategy::Skyscraper,
+            "two-string-kite" => strategy::TwoStringKite,
+            "empty-rectangle" => strategy::EmptyRectangle,
+            "w-wing" => strategy::WWing,
+            "simple-coloring" => strategy::SimpleColoring,
+            "multi-coloring" => strategy::MultiColoring,
+            "medusa" => strategy::Medusa,
+            "x-cycle" => strategy::XCycle,
+            "aic" => strategy::AIC,
+            "als-xz" => strategy::AlsXZ,
+            "als-xy-wing" => strategy::AlsXYWing,
+            "death-blossom" => strategy::DeathBlossom,
+            "sue-de-coq" => strategy::SueDeCoq,
+            "unique-rectangle" => strategy::UniqueRectangle,
+            "bug" => strategy::BUG,
+            "templates" => strategy::Templates,
+            "template-pairs" => strategy::TemplatePairs,
+            "cell-forcing-chains" => strategy::CellForcingChains,
+            "unit-forcing-chains" => strategy::UnitForcingChains,
+            "contradiction-chains" => strategy::ContradictionChains,
+            "brute-force" => strategy::BruteForce,
+            other => return Err(format!("Unknown strategy \"{}\"", other))
+        };
+        if !strategies.contains(&selected) {
+            strategies.push(selected);
         }
     }
-    println!("Main time: {}", (SystemTime::now().duration_since(start).expect("Time")).as_micros());
+    return Ok(strategies);
 }
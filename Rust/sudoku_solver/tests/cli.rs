@@ -0,0 +1,66 @@
+// The names follow the convention of the Go version of the solver
+#![allow(non_snake_case)]
+#![allow(clippy::needless_return)]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// Sudoku of the Wikipedia page about sudokus, which has a unique solution
+const PUZZLE: &str = "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+// The definitive values of the first row force a 9 in the last cell, which is already in its column
+const NO_SOLUTION: &str = "12345678.........9...............................................................";
+// Solution of the first sudoku without the four cells of a rectangle, whose two values can be exchanged
+const MULTIPLE_SOLUTIONS: &str = "534..8912672195348198342567859..1423426853791713924856961537284287419635345286179";
+const EMPTY: &str = ".................................................................................";
+
+// Run the program with the given arguments and the sudoku in the standard input, and return its exit code
+fn exitCode(args: &[&str], sudokuString: &str) -> i32 {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sudoku_solver")).args(args)
+        .stdin(Stdio::piped()).stdout(Stdio::null()).stderr(Stdio::null()).spawn().unwrap();
+    // With wrong arguments the program ends without reading the sudoku, so the write can fail
+    let _ = child.stdin.take().unwrap().write_all(sudokuString.as_bytes());
+    return child.wait().unwrap().code().unwrap();
+}
+
+#[test]
+fn solvedSudokuExitsWithSuccess() {
+    assert_eq!(exitCode(&["solve"], PUZZLE), 0);
+    assert_eq!(exitCode(&["validate"], PUZZLE), 0);
+    assert_eq!(exitCode(&["solutions"], PUZZLE), 0);
+}
+
+#[test]
+fn wrongArgumentsExitWithUsage() {
+    assert_eq!(exitCode(&[], PUZZLE), 1);
+    assert_eq!(exitCode(&["solve", "--unknown"], PUZZLE), 1);
+    assert_eq!(exitCode(&["solve", "-s", "unknown"], PUZZLE), 1);
+    assert_eq!(exitCode(&["solutions", "-n", "0"], PUZZLE), 1);
+    assert_eq!(exitCode(&["solve", "-c", "0"], PUZZLE), 1);
+}
+
+#[test]
+fn invalidSudokuExitsWithInvalid() {
+    assert_eq!(exitCode(&["solve"], "53x"), 2);
+    assert_eq!(exitCode(&["solve"], &PUZZLE[..80]), 2);
+    // Two 5 in the first row
+    assert_eq!(exitCode(&["validate"], &format!("535{}", &PUZZLE[3..])), 2);
+}
+
+#[test]
+fn unsolvedSudokuExitsWithUnsolved() {
+    assert_eq!(exitCode(&["solve", "-s", "constraints"], EMPTY), 3);
+}
+
+#[test]
+fn sudokuWithoutSolutionExitsWithNoSolution() {
+    assert_eq!(exitCode(&["solve"], NO_SOLUTION), 4);
+    assert_eq!(exitCode(&["validate"], NO_SOLUTION), 4);
+    assert_eq!(exitCode(&["solutions"], NO_SOLUTION), 4);
+}
+
+#[test]
+fn sudokuWithMoreSolutionsExitsWithMultipleSolutions() {
+    assert_eq!(exitCode(&["validate"], MULTIPLE_SOLUTIONS), 5);
+    assert_eq!(exitCode(&["solve", "-u"], MULTIPLE_SOLUTIONS), 5);
+    assert_eq!(exitCode(&["solutions", "-n", "1"], MULTIPLE_SOLUTIONS), 0);
+}